@@ -1,4 +1,4 @@
-use crate::abi::call::{ArgAbi, FnAbi, Reg, RegKind, Uniform};
+use crate::abi::call::{ArgAbi, ArgExtension, FnAbi, Reg, RegKind, Uniform};
 use crate::abi::{HasDataLayout, TyAbiInterface};
 
 fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Option<Uniform>
@@ -6,11 +6,12 @@ fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Opti
     Ty: TyAbiInterface<'a, C> + Copy,
     C: HasDataLayout,
 {
-    arg.layout.homogeneous_aggregate(cx).ok().and_then(|ha| ha.unit()).and_then(|unit| {
+    arg.layout.homogeneous_aggregate(cx).ok().and_then(|ha| {
+        let unit = ha.unit()?;
         let size = arg.layout.size;
 
         // Ensure we have at most four uniquely addressable members.
-        if size > unit.size.checked_mul(4, cx).unwrap() {
+        if ha.unit_count()? > 4 {
             return None;
         }
 
@@ -20,7 +21,7 @@ fn is_homogeneous_aggregate<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>) -> Opti
             RegKind::Vector => size.bits() == 64 || size.bits() == 128,
         };
 
-        valid_unit.then_some(Uniform { unit, total: size })
+        valid_unit.then_some(Uniform { unit, total: size, trailing_ext: ArgExtension::None })
     })
 }
 
@@ -40,7 +41,7 @@ fn classify_ret<'a, Ty, C>(cx: &C, ret: &mut ArgAbi<'a, Ty>)
     let size = ret.layout.size;
     let bits = size.bits();
     if bits <= 128 {
-        ret.cast_to(Uniform { unit: Reg::i64(), total: size });
+        ret.cast_to(Uniform { unit: Reg::i64(), total: size, trailing_ext: ArgExtension::None });
         return;
     }
     ret.make_indirect();
@@ -62,7 +63,7 @@ fn classify_arg<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>)
     let size = arg.layout.size;
     let bits = size.bits();
     if bits <= 128 {
-        arg.cast_to(Uniform { unit: Reg::i64(), total: size });
+        arg.cast_to(Uniform { unit: Reg::i64(), total: size, trailing_ext: ArgExtension::None });
         return;
     }
     arg.make_indirect();
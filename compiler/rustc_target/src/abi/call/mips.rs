@@ -1,4 +1,4 @@
-use crate::abi::call::{ArgAbi, FnAbi, Reg, Uniform};
+use crate::abi::call::{ArgAbi, ArgExtension, FnAbi, Reg, Uniform};
 use crate::abi::{HasDataLayout, Size};
 
 fn classify_ret<Ty, C>(cx: &C, ret: &mut ArgAbi<'_, Ty>, offset: &mut Size)
@@ -22,7 +22,7 @@ fn classify_arg<Ty, C>(cx: &C, arg: &mut ArgAbi<'_, Ty>, offset: &mut Size)
     let align = arg.layout.align.max(dl.i32_align).min(dl.i64_align).abi;
 
     if arg.layout.is_aggregate() {
-        arg.cast_to(Uniform { unit: Reg::i32(), total: size });
+        arg.cast_to(Uniform { unit: Reg::i32(), total: size, trailing_ext: ArgExtension::None });
         if !offset.is_aligned(align) {
             arg.pad_with(Reg::i32());
         }
@@ -27,6 +27,9 @@
 mod x86_64;
 mod x86_win64;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, HashStable_Generic)]
 pub enum PassMode {
     /// Ignore the argument.
@@ -53,6 +56,45 @@ pub enum PassMode {
     Indirect { attrs: ArgAttributes, extra_attrs: Option<ArgAttributes>, on_stack: bool },
 }
 
+impl PassMode {
+    /// Returns a short description of this pass mode, such as `direct`,
+    /// `cast(prefix=1,rest=i64x2)` or `indirect(byval)`. Unlike the `Debug`
+    /// output, this leaves out the attributes, so it's suitable for tests.
+    pub fn describe(&self) -> String {
+        match self {
+            PassMode::Ignore => "ignore".to_string(),
+            PassMode::Direct(_) => "direct".to_string(),
+            PassMode::Pair(..) => "pair".to_string(),
+            PassMode::Cast(cast) => {
+                let unit = cast.rest.unit;
+                let kind = match unit.kind {
+                    RegKind::Integer => 'i',
+                    RegKind::Float => 'f',
+                    RegKind::Vector => 'v',
+                };
+                let unit_size = unit.size.bytes();
+                let count = if unit_size == 0 {
+                    0
+                } else {
+                    (cast.rest.total.bytes() + unit_size - 1) / unit_size
+                };
+                format!(
+                    "cast(prefix={},rest={}{}x{})",
+                    cast.prefix_count(),
+                    kind,
+                    unit.size.bits(),
+                    count
+                )
+            }
+            PassMode::Indirect { extra_attrs: None, on_stack: false, .. } => "indirect".to_string(),
+            PassMode::Indirect { extra_attrs: None, on_stack: true, .. } => {
+                "indirect(byval)".to_string()
+            }
+            PassMode::Indirect { extra_attrs: Some(_), .. } => "indirect(ptr+meta)".to_string(),
+        }
+    }
+}
+
 // Hack to disable non_upper_case_globals only for the bitflags! and not for the rest
 // of this module
 pub use attr_impl::ArgAttribute;
@@ -122,11 +164,48 @@ pub fn ext(&mut self, ext: ArgExtension) -> &mut Self {
         self
     }
 
+    /// Like `ext`, but keeps the current extension if one is already set
+    /// instead of panicking on a conflict.
+    pub fn ext_or_keep(&mut self, ext: ArgExtension) -> &mut Self {
+        if self.arg_ext == ArgExtension::None {
+            self.arg_ext = ext;
+        }
+        self
+    }
+
+    pub fn clear_ext(&mut self) -> &mut Self {
+        self.arg_ext = ArgExtension::None;
+        self
+    }
+
+    /// Adds the attributes of `other`, keeping the larger `pointee_size` and
+    /// `pointee_align` of the two. An extension set in `other` must agree with
+    /// the one in `self`, if any; like `ext`, this panics on a conflict.
+    pub fn merge(&mut self, other: &ArgAttributes) -> &mut Self {
+        self.regular |= other.regular;
+        self.pointee_size = self.pointee_size.max(other.pointee_size);
+        self.pointee_align = self.pointee_align.max(other.pointee_align);
+        if other.arg_ext != ArgExtension::None {
+            self.ext(other.arg_ext);
+        }
+        self
+    }
+
     pub fn set(&mut self, attr: ArgAttribute) -> &mut Self {
         self.regular |= attr;
         self
     }
 
+    pub fn remove(&mut self, attr: ArgAttribute) -> &mut Self {
+        self.regular &= !attr;
+        self
+    }
+
+    pub fn toggle(&mut self, attr: ArgAttribute) -> &mut Self {
+        self.regular ^= attr;
+        self
+    }
+
     pub fn contains(&self, attr: ArgAttribute) -> bool {
         self.regular.contains(attr)
     }
@@ -139,6 +218,20 @@ pub enum RegKind {
     Vector,
 }
 
+impl RegKind {
+    pub fn is_integer(self) -> bool {
+        matches!(self, RegKind::Integer)
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, RegKind::Float)
+    }
+
+    pub fn is_vector(self) -> bool {
+        matches!(self, RegKind::Vector)
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, HashStable_Generic)]
 pub struct Reg {
     pub kind: RegKind,
@@ -162,6 +255,60 @@ impl Reg {
 
     reg_ctor!(f32, Float, 32);
     reg_ctor!(f64, Float, 64);
+
+    /// Returns the integer register exactly `size` wide, if there is one.
+    pub fn from_integer_size(size: Size) -> Option<Reg> {
+        match size.bits() {
+            8 => Some(Reg::i8()),
+            16 => Some(Reg::i16()),
+            32 => Some(Reg::i32()),
+            64 => Some(Reg::i64()),
+            128 => Some(Reg::i128()),
+            _ => None,
+        }
+    }
+
+    /// Returns the float register exactly `size` wide, if there is one.
+    pub fn from_float_size(size: Size) -> Option<Reg> {
+        match size.bits() {
+            32 => Some(Reg::f32()),
+            64 => Some(Reg::f64()),
+            _ => None,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.kind.is_integer()
+    }
+
+    pub fn is_float(&self) -> bool {
+        self.kind.is_float()
+    }
+
+    pub fn is_vector(&self) -> bool {
+        self.kind.is_vector()
+    }
+
+    pub fn same_size_as(&self, other: &Reg) -> bool {
+        self.size == other.size
+    }
+
+    /// Returns `true` if `self` and `other` can be passed interchangeably by ABIs
+    /// which move floats through integer registers (and vice versa). This is a
+    /// weaker relation than `==`: integer and float registers of the same size
+    /// are compatible, while vector registers are only compatible with equal ones.
+    pub fn abi_compatible(&self, other: &Reg) -> bool {
+        self == other || (self.same_size_as(other) && !self.is_vector() && !other.is_vector())
+    }
+
+    /// A cast to `count` consecutive copies of this register.
+    pub fn repeated(self, count: u64) -> CastTarget {
+        CastTarget::from(Uniform {
+            unit: self,
+            total: self.size * count,
+            trailing_ext: ArgExtension::None,
+        })
+    }
 }
 
 impl Reg {
@@ -182,6 +329,8 @@ pub fn align<C: HasDataLayout>(&self, cx: &C) -> Align {
                 64 => dl.f64_align.abi,
                 _ => panic!("unsupported float: {:?}", self),
             },
+            // Sizes missing from the data layout, e.g. 512-bit vectors when it
+            // only goes up to 128 bits, get their natural alignment like in LLVM.
             RegKind::Vector => dl.vector_align(self.size).abi,
         }
     }
@@ -200,11 +349,16 @@ pub struct Uniform {
     ///   can be shorter, i.e., `{ i64, i64, i32 }` for
     ///   64-bit integers with a total size of 20 bytes.
     pub total: Size,
+
+    /// How a shorter trailing integer element (the `i32` in the example
+    /// above) should be extended, if the ABI requires it to be.
+    /// `ArgExtension::None` leaves it as-is.
+    pub trailing_ext: ArgExtension,
 }
 
 impl From<Reg> for Uniform {
     fn from(unit: Reg) -> Uniform {
-        Uniform { unit, total: unit.size }
+        Uniform { unit, total: unit.size, trailing_ext: ArgExtension::None }
     }
 }
 
@@ -256,24 +410,83 @@ pub fn pair(a: Reg, b: Reg) -> CastTarget {
         }
     }
 
-    pub fn size<C: HasDataLayout>(&self, _cx: &C) -> Size {
-        let mut size = self.rest.total;
-        for i in 0..self.prefix.iter().count() {
-            match self.prefix[i] {
-                Some(v) => size += Size { raw: v.size.bytes() },
-                None => {}
-            }
+    /// Creates a cast with the given prefix registers, followed by `rest`.
+    /// The attributes are left empty, as with `CastTarget::from(rest)`.
+    pub fn prefixed(prefix: [Option<Reg>; 8], rest: Uniform) -> CastTarget {
+        CastTarget { prefix, ..CastTarget::from(rest) }
+    }
+
+    /// Creates a cast to the given registers, in order. At most 8 of them fit in
+    /// the prefix; any further registers are collapsed into `rest`, so they must
+    /// all be the same register. With 8 registers or fewer, `rest` is empty
+    /// (a `total` of zero).
+    pub fn from_registers(regs: &[Reg]) -> CastTarget {
+        assert!(!regs.is_empty(), "cannot cast to no registers");
+        let mut prefix = [None; 8];
+        for (slot, &reg) in prefix.iter_mut().zip(regs) {
+            *slot = Some(reg);
         }
-        return size;
+        let rest = match regs.get(8..) {
+            Some(tail @ [unit, ..]) => {
+                assert!(
+                    tail.iter().all(|reg| reg == unit),
+                    "registers after the first 8 must all be the same, found {:?}",
+                    tail
+                );
+                Uniform {
+                    unit: *unit,
+                    total: unit.size * tail.len() as u64,
+                    trailing_ext: ArgExtension::None,
+                }
+            }
+            _ => Uniform {
+                unit: regs[regs.len() - 1],
+                total: Size::ZERO,
+                trailing_ext: ArgExtension::None,
+            },
+        };
+        CastTarget::prefixed(prefix, rest)
+    }
+
+    pub fn with_attrs(mut self, attrs: ArgAttributes) -> CastTarget {
+        self.attrs = attrs;
+        self
+    }
+
+    /// Returns the number of prefix registers in use.
+    pub fn prefix_count(&self) -> usize {
+        self.prefix.iter().filter(|reg| reg.is_some()).count()
+    }
+
+    /// Returns the number of `rest.unit` registers needed to hold `rest.total`,
+    /// counting a shorter trailing integer as a full register.
+    pub fn rest_count<C: HasDataLayout>(&self, _cx: &C) -> u64 {
+        let unit_size = self.rest.unit.size.bytes();
+        if unit_size == 0 { 0 } else { (self.rest.total.bytes() + unit_size - 1) / unit_size }
+    }
+
+    /// Returns the size of the prefix registers plus `rest.total`. This is not
+    /// rounded up to a multiple of `rest.unit.size`, so a shorter trailing
+    /// integer only counts with its actual size; see `rest_count` for the number
+    /// of registers it occupies.
+    pub fn size<C: HasDataLayout>(&self, cx: &C) -> Size {
+        self.size_align(cx).0
     }
 
     pub fn align<C: HasDataLayout>(&self, cx: &C) -> Align {
-        self.prefix
-            .iter()
-            .filter_map(|x| x.map(|reg| reg.align(cx)))
-            .fold(cx.data_layout().aggregate_align.abi.max(self.rest.align(cx)), |acc, align| {
-                acc.max(align)
-            })
+        self.size_align(cx).1
+    }
+
+    /// Returns both the `size` and the `align` of this cast, going over the
+    /// prefix only once.
+    pub fn size_align<C: HasDataLayout>(&self, cx: &C) -> (Size, Align) {
+        let mut size = self.rest.total;
+        let mut align = cx.data_layout().aggregate_align.abi.max(self.rest.align(cx));
+        for reg in self.prefix.iter().flatten() {
+            size += reg.size;
+            align = align.max(reg.align(cx));
+        }
+        (size, align)
     }
 }
 
@@ -281,8 +494,9 @@ pub fn align<C: HasDataLayout>(&self, cx: &C) -> Align {
 #[derive(Copy, Clone, Debug)]
 pub enum HomogeneousAggregate {
     /// Yes, all the "leaf fields" of this struct are passed in the
-    /// same way (specified in the `Reg` value).
-    Homogeneous(Reg),
+    /// same way (specified in the `Reg` value), and together they
+    /// take up `total` bytes.
+    Homogeneous { unit: Reg, total: Size },
 
     /// There are no leaf fields at all.
     NoData,
@@ -292,14 +506,34 @@ pub enum HomogeneousAggregate {
 /// there are distinct leaf fields passed in different ways,
 /// or this is uninhabited.
 #[derive(Copy, Clone, Debug)]
-pub struct Heterogeneous;
+pub struct Heterogeneous {
+    /// Whether the layout is uninhabited, as opposed to having leaf fields
+    /// of different kinds (or padding between them).
+    pub uninhabited: bool,
+}
+
+impl Heterogeneous {
+    /// Leaf fields of different kinds, or padding between them.
+    const MIXED: Heterogeneous = Heterogeneous { uninhabited: false };
+}
 
 impl HomogeneousAggregate {
     /// If this is a homogeneous aggregate, returns the homogeneous
     /// unit, else `None`.
     pub fn unit(self) -> Option<Reg> {
         match self {
-            HomogeneousAggregate::Homogeneous(reg) => Some(reg),
+            HomogeneousAggregate::Homogeneous { unit, .. } => Some(unit),
+            HomogeneousAggregate::NoData => None,
+        }
+    }
+
+    /// If this is a homogeneous aggregate, returns how many of its
+    /// homogeneous units it is made of, else `None`.
+    pub fn unit_count(self) -> Option<u64> {
+        match self {
+            HomogeneousAggregate::Homogeneous { unit, total } => {
+                Some(total.bytes() / unit.size.bytes())
+            }
             HomogeneousAggregate::NoData => None,
         }
     }
@@ -307,15 +541,26 @@ pub fn unit(self) -> Option<Reg> {
     /// Try to combine two `HomogeneousAggregate`s, e.g. from two fields in
     /// the same `struct`. Only succeeds if only one of them has any data,
     /// or both units are identical.
-    fn merge(self, other: HomogeneousAggregate) -> Result<HomogeneousAggregate, Heterogeneous> {
+    ///
+    /// The extents of the two are summed, unless they `overlap` (e.g. they
+    /// are the members of a `union`), in which case the larger one is kept.
+    fn merge(
+        self,
+        other: HomogeneousAggregate,
+        overlap: bool,
+    ) -> Result<HomogeneousAggregate, Heterogeneous> {
         match (self, other) {
             (x, HomogeneousAggregate::NoData) | (HomogeneousAggregate::NoData, x) => Ok(x),
 
-            (HomogeneousAggregate::Homogeneous(a), HomogeneousAggregate::Homogeneous(b)) => {
+            (
+                HomogeneousAggregate::Homogeneous { unit: a, total: a_total },
+                HomogeneousAggregate::Homogeneous { unit: b, total: b_total },
+            ) => {
                 if a != b {
-                    return Err(Heterogeneous);
+                    return Err(Heterogeneous::MIXED);
                 }
-                Ok(self)
+                let total = if overlap { a_total.max(b_total) } else { a_total + b_total };
+                Ok(HomogeneousAggregate::Homogeneous { unit: a, total })
             }
         }
     }
@@ -340,11 +585,24 @@ fn is_aggregate(&self) -> bool {
     /// should generally only be relevant to the ABI details of
     /// specific targets.
     pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, Heterogeneous>
+    where
+        Ty: TyAbiInterface<'a, C> + Copy,
+    {
+        self.homogeneous_aggregate_detailed(cx).map(|(result, _)| result)
+    }
+
+    /// Like `homogeneous_aggregate`, but also returns whether a union (at any
+    /// depth) contributed to the result, i.e. whether some of the units overlap.
+    /// Enum variants are not considered unions here.
+    pub fn homogeneous_aggregate_detailed<C>(
+        &self,
+        cx: &C,
+    ) -> Result<(HomogeneousAggregate, bool), Heterogeneous>
     where
         Ty: TyAbiInterface<'a, C> + Copy,
     {
         match self.abi {
-            Abi::Uninhabited => Err(Heterogeneous),
+            Abi::Uninhabited => Err(Heterogeneous { uninhabited: true }),
 
             // The primitive for this algorithm.
             Abi::Scalar(scalar) => {
@@ -352,21 +610,21 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
                     abi::Int(..) | abi::Pointer => RegKind::Integer,
                     abi::F32 | abi::F64 => RegKind::Float,
                 };
-                Ok(HomogeneousAggregate::Homogeneous(Reg { kind, size: self.size }))
+                let unit = Reg { kind, size: self.size };
+                Ok((HomogeneousAggregate::Homogeneous { unit, total: self.size }, false))
             }
 
             Abi::Vector { .. } => {
                 assert!(!self.is_zst());
-                Ok(HomogeneousAggregate::Homogeneous(Reg {
-                    kind: RegKind::Vector,
-                    size: self.size,
-                }))
+                let unit = Reg { kind: RegKind::Vector, size: self.size };
+                Ok((HomogeneousAggregate::Homogeneous { unit, total: self.size }, false))
             }
 
             Abi::ScalarPair(..) | Abi::Aggregate { .. } => {
                 // Helper for computing `homogeneous_aggregate`, allowing a custom
                 // starting offset (used below for handling variants).
-                let from_fields_at =
+                let mut has_union = false;
+                let mut from_fields_at =
                     |layout: Self,
                      start: Size|
                      -> Result<(HomogeneousAggregate, Size), Heterogeneous> {
@@ -378,7 +636,16 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
                                 assert_eq!(start, Size::ZERO);
 
                                 let result = if count > 0 {
-                                    layout.field(cx, 0).homogeneous_aggregate(cx)?
+                                    let (field_result, field_has_union) =
+                                        layout.field(cx, 0).homogeneous_aggregate_detailed(cx)?;
+                                    has_union |= field_has_union;
+                                    match field_result {
+                                        HomogeneousAggregate::Homogeneous { unit, total } => {
+                                            let total = total * count;
+                                            HomogeneousAggregate::Homogeneous { unit, total }
+                                        }
+                                        no_data => no_data,
+                                    }
                                 } else {
                                     HomogeneousAggregate::NoData
                                 };
@@ -390,15 +657,19 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
 
                         let mut result = HomogeneousAggregate::NoData;
                         let mut total = start;
+                        has_union |= is_union;
 
                         for i in 0..layout.fields.count() {
                             if !is_union && total != layout.fields.offset(i) {
-                                return Err(Heterogeneous);
+                                return Err(Heterogeneous::MIXED);
                             }
 
                             let field = layout.field(cx, i);
 
-                            result = result.merge(field.homogeneous_aggregate(cx)?)?;
+                            let (field_result, field_has_union) =
+                                field.homogeneous_aggregate_detailed(cx)?;
+                            result = result.merge(field_result, is_union)?;
+                            has_union |= field_has_union;
 
                             // Keep track of the offset (without padding).
                             let size = field.size;
@@ -430,29 +701,31 @@ pub fn homogeneous_aggregate<C>(&self, cx: &C) -> Result<HomogeneousAggregate, H
                         // (the offsets of variant fields should be identical
                         // between the two for either to be a homogeneous aggregate).
                         let variant_start = total;
+                        let mut variants_result = HomogeneousAggregate::NoData;
                         for variant_idx in variants.indices() {
                             let (variant_result, variant_total) =
                                 from_fields_at(self.for_variant(cx, variant_idx), variant_start)?;
 
-                            result = result.merge(variant_result)?;
+                            variants_result = variants_result.merge(variant_result, true)?;
                             total = total.max(variant_total);
                         }
+                        result = result.merge(variants_result, false)?;
                     }
                 }
 
                 // There needs to be no padding.
                 if total != self.size {
-                    Err(Heterogeneous)
+                    Err(Heterogeneous::MIXED)
                 } else {
                     match result {
-                        HomogeneousAggregate::Homogeneous(_) => {
+                        HomogeneousAggregate::Homogeneous { .. } => {
                             assert_ne!(total, Size::ZERO);
                         }
                         HomogeneousAggregate::NoData => {
                             assert_eq!(total, Size::ZERO);
                         }
                     }
-                    Ok(result)
+                    Ok((result, has_union))
                 }
             }
         }
@@ -490,6 +763,21 @@ pub fn new(
         ArgAbi { layout, pad: None, mode }
     }
 
+    /// Returns the offset of the element `index` (0 or 1) of an argument passed
+    /// as a `PassMode::Pair`, which is also the offset `ArgAbi::new` computes
+    /// that element's attributes for.
+    pub fn scalar_pair_element_offset<C: HasDataLayout>(&self, cx: &C, index: usize) -> Size {
+        assert!(self.is_pair(), "{:?} is not passed as a pair", self.mode);
+        let Abi::ScalarPair(a, b) = self.layout.abi else {
+            panic!("pair argument without a `ScalarPair` layout: {:?}", self.layout.abi)
+        };
+        match index {
+            0 => Size::ZERO,
+            1 => a.size(cx).align_to(b.align(cx).abi),
+            _ => panic!("scalar pairs have no element {}", index),
+        }
+    }
+
     fn indirect_pass_mode(layout: &TyAndLayout<'a, Ty>) -> PassMode {
         let mut attrs = ArgAttributes::new();
 
@@ -531,27 +819,54 @@ pub fn make_indirect_byval(&mut self) {
         }
     }
 
+    /// Forces the argument to be ignored, i.e. not passed at all.
+    ///
+    /// This must only be used for arguments that carry no data, i.e. ZSTs or
+    /// uninhabited types; otherwise the value would be silently dropped.
+    pub fn make_ignore(&mut self) {
+        debug_assert!(
+            self.layout.is_zst() || self.layout.abi.is_uninhabited(),
+            "Tried to ignore an argument carrying data: {:?}",
+            self.layout
+        );
+        self.mode = PassMode::Ignore;
+    }
+
     pub fn extend_integer_width_to(&mut self, bits: u64) {
-        // Only integers have signedness
-        if let Abi::Scalar(scalar) = self.layout.abi {
+        fn extend_scalar(scalar: abi::Scalar, attrs: &mut ArgAttributes, bits: u64) {
+            // Only integers have signedness
             if let abi::Int(i, signed) = scalar.primitive() {
                 if i.size().bits() < bits {
-                    if let PassMode::Direct(ref mut attrs) = self.mode {
-                        if signed {
-                            attrs.ext(ArgExtension::Sext)
-                        } else {
-                            attrs.ext(ArgExtension::Zext)
-                        };
-                    }
+                    if signed {
+                        attrs.ext(ArgExtension::Sext)
+                    } else {
+                        attrs.ext(ArgExtension::Zext)
+                    };
                 }
             }
         }
+
+        match (self.layout.abi, &mut self.mode) {
+            (Abi::Scalar(scalar), PassMode::Direct(attrs)) => extend_scalar(scalar, attrs, bits),
+            (Abi::ScalarPair(a, b), PassMode::Pair(a_attrs, b_attrs)) => {
+                extend_scalar(a, a_attrs, bits);
+                extend_scalar(b, b_attrs, bits);
+            }
+            _ => {}
+        }
     }
 
     pub fn cast_to<T: Into<CastTarget>>(&mut self, target: T) {
         self.mode = PassMode::Cast(target.into());
     }
 
+    /// Like `cast_to`, but also marks the cast registers as `inreg`.
+    pub fn cast_to_inreg<T: Into<CastTarget>>(&mut self, target: T) {
+        let mut cast = target.into();
+        cast.attrs.set(ArgAttribute::InReg);
+        self.mode = PassMode::Cast(cast);
+    }
+
     pub fn pad_with(&mut self, reg: Reg) {
         self.pad = Some(reg);
     }
@@ -571,6 +886,31 @@ pub fn is_unsized_indirect(&self) -> bool {
     pub fn is_ignore(&self) -> bool {
         matches!(self.mode, PassMode::Ignore)
     }
+
+    /// For arguments passed indirectly, returns whether the pointer is marked as
+    /// `NoAlias`, i.e. whether it points to a copy owned by the callee. Returns
+    /// `None` for any other mode.
+    pub fn indirect_is_noalias(&self) -> Option<bool> {
+        match self.mode {
+            PassMode::Indirect { attrs, .. } => Some(attrs.contains(ArgAttribute::NoAlias)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the argument is passed as `PassMode::Direct`.
+    pub fn is_direct(&self) -> bool {
+        matches!(self.mode, PassMode::Direct(_))
+    }
+
+    /// Returns `true` if the argument is passed as `PassMode::Pair`.
+    pub fn is_pair(&self) -> bool {
+        matches!(self.mode, PassMode::Pair(..))
+    }
+
+    /// Returns `true` if the argument is passed as `PassMode::Cast`.
+    pub fn is_cast(&self) -> bool {
+        matches!(self.mode, PassMode::Cast(_))
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, HashStable_Generic)]
@@ -602,6 +942,159 @@ pub enum Conv {
     AvrNonBlockingInterrupt,
 }
 
+impl Conv {
+    /// Returns `true` for the general language calling conventions, which every
+    /// target's backend is expected to support.
+    pub fn is_general(&self) -> bool {
+        match self {
+            Conv::C | Conv::Rust => true,
+            Conv::ArmAapcs
+            | Conv::CCmseNonSecureCall
+            | Conv::Msp430Intr
+            | Conv::PtxKernel
+            | Conv::X86Fastcall
+            | Conv::X86Intr
+            | Conv::X86Stdcall
+            | Conv::X86ThisCall
+            | Conv::X86VectorCall
+            | Conv::X86_64SysV
+            | Conv::X86_64Win64
+            | Conv::AmdGpuKernel
+            | Conv::AvrInterrupt
+            | Conv::AvrNonBlockingInterrupt => false,
+        }
+    }
+
+    /// Returns `true` for calling conventions which only exist on some targets.
+    pub fn is_target_specific(&self) -> bool {
+        !self.is_general()
+    }
+
+    /// Returns `true` if this is the calling convention of an interrupt handler.
+    pub fn is_interrupt(&self) -> bool {
+        match self {
+            Conv::Msp430Intr
+            | Conv::X86Intr
+            | Conv::AvrInterrupt
+            | Conv::AvrNonBlockingInterrupt => true,
+            Conv::C
+            | Conv::Rust
+            | Conv::ArmAapcs
+            | Conv::CCmseNonSecureCall
+            | Conv::PtxKernel
+            | Conv::X86Fastcall
+            | Conv::X86Stdcall
+            | Conv::X86ThisCall
+            | Conv::X86VectorCall
+            | Conv::X86_64SysV
+            | Conv::X86_64Win64
+            | Conv::AmdGpuKernel => false,
+        }
+    }
+
+    /// Returns `true` if functions with this calling convention can never
+    /// unwind, i.e. their `FnAbi::can_unwind` must be `false`.
+    pub fn disallows_unwinding(&self) -> bool {
+        match self {
+            Conv::CCmseNonSecureCall
+            | Conv::Msp430Intr
+            | Conv::PtxKernel
+            | Conv::X86Intr
+            | Conv::AmdGpuKernel
+            | Conv::AvrInterrupt
+            | Conv::AvrNonBlockingInterrupt => true,
+            Conv::C
+            | Conv::Rust
+            | Conv::ArmAapcs
+            | Conv::X86Fastcall
+            | Conv::X86Stdcall
+            | Conv::X86ThisCall
+            | Conv::X86VectorCall
+            | Conv::X86_64SysV
+            | Conv::X86_64Win64 => false,
+        }
+    }
+
+    /// Returns `true` if `FnAbi::adjust_for_foreign_abi` can lower functions with
+    /// this calling convention on the architecture `arch` (`Target::arch`).
+    pub fn supported_on_arch(&self, arch: &str) -> bool {
+        use ForeignAbiArch::*;
+        let Some(arch) = ForeignAbiArch::from_target_arch(arch) else {
+            return false;
+        };
+        match self {
+            Conv::C | Conv::Rust => true,
+            Conv::ArmAapcs => arch == Arm,
+            Conv::CCmseNonSecureCall => matches!(arch, Arm | Aarch64),
+            Conv::Msp430Intr => arch == Msp430,
+            Conv::PtxKernel => arch == Nvptx64,
+            Conv::X86Fastcall | Conv::X86Stdcall | Conv::X86ThisCall => arch == X86,
+            Conv::X86Intr | Conv::X86VectorCall => matches!(arch, X86 | X86_64),
+            Conv::X86_64SysV | Conv::X86_64Win64 => arch == X86_64,
+            Conv::AmdGpuKernel => arch == AmdGpu,
+            Conv::AvrInterrupt | Conv::AvrNonBlockingInterrupt => arch == Avr,
+        }
+    }
+}
+
+/// The architectures `FnAbi::adjust_for_foreign_abi` knows how to lower foreign
+/// calling conventions for. Anything else is rejected up front.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ForeignAbiArch {
+    X86,
+    X86_64,
+    Aarch64,
+    AmdGpu,
+    Arm,
+    Avr,
+    M68k,
+    Mips,
+    Mips64,
+    PowerPc,
+    PowerPc64,
+    S390x,
+    Msp430,
+    Sparc,
+    Sparc64,
+    Nvptx,
+    Nvptx64,
+    Hexagon,
+    RiscV,
+    Wasm,
+    AsmJs,
+    Bpf,
+}
+
+impl ForeignAbiArch {
+    fn from_target_arch(arch: &str) -> Option<ForeignAbiArch> {
+        Some(match arch {
+            "x86" => ForeignAbiArch::X86,
+            "x86_64" => ForeignAbiArch::X86_64,
+            "aarch64" => ForeignAbiArch::Aarch64,
+            "amdgpu" => ForeignAbiArch::AmdGpu,
+            "arm" => ForeignAbiArch::Arm,
+            "avr" => ForeignAbiArch::Avr,
+            "m68k" => ForeignAbiArch::M68k,
+            "mips" => ForeignAbiArch::Mips,
+            "mips64" => ForeignAbiArch::Mips64,
+            "powerpc" => ForeignAbiArch::PowerPc,
+            "powerpc64" => ForeignAbiArch::PowerPc64,
+            "s390x" => ForeignAbiArch::S390x,
+            "msp430" => ForeignAbiArch::Msp430,
+            "sparc" => ForeignAbiArch::Sparc,
+            "sparc64" => ForeignAbiArch::Sparc64,
+            "nvptx" => ForeignAbiArch::Nvptx,
+            "nvptx64" => ForeignAbiArch::Nvptx64,
+            "hexagon" => ForeignAbiArch::Hexagon,
+            "riscv32" | "riscv64" => ForeignAbiArch::RiscV,
+            "wasm32" | "wasm64" => ForeignAbiArch::Wasm,
+            "asmjs" => ForeignAbiArch::AsmJs,
+            "bpf" => ForeignAbiArch::Bpf,
+            _ => return None,
+        })
+    }
+}
+
 /// Metadata describing how the arguments to a native function
 /// should be passed in order to respect the native ABI.
 ///
@@ -633,6 +1126,8 @@ pub struct FnAbi<'a, Ty> {
 pub enum AdjustForForeignAbiError {
     /// Target architecture doesn't support "foreign" (i.e. non-Rust) ABIs.
     Unsupported { arch: Symbol, abi: spec::abi::Abi },
+    /// Target architecture supports "foreign" ABIs, but not this particular one.
+    UnsupportedAbiForArch { arch: Symbol, abi: spec::abi::Abi },
 }
 
 impl fmt::Display for AdjustForForeignAbiError {
@@ -641,11 +1136,97 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::Unsupported { arch, abi } => {
                 write!(f, "target architecture {:?} does not support `extern {}` ABI", arch, abi)
             }
+            Self::UnsupportedAbiForArch { arch, abi } => {
+                write!(f, "`extern {}` ABI is not supported on target architecture {:?}", abi, arch)
+            }
         }
     }
 }
 
 impl<'a, Ty> FnAbi<'a, Ty> {
+    /// Creates the `FnAbi` of a function which isn't C-variadic and may unwind,
+    /// with `ret` and `args` passed as given.
+    pub fn new_internal(ret: ArgAbi<'a, Ty>, args: Vec<ArgAbi<'a, Ty>>, conv: Conv) -> Self {
+        let fixed_count = args.len();
+        FnAbi { args, ret, c_variadic: false, fixed_count, conv, can_unwind: true }
+    }
+
+    /// Returns the non-variadic arguments. For functions which aren't C-variadic,
+    /// this is every argument, including any implicit trailing ones (e.g. the caller
+    /// location of `#[track_caller]` functions).
+    pub fn fixed_args(&self) -> &[ArgAbi<'a, Ty>] {
+        assert!(self.fixed_count <= self.args.len());
+        if self.c_variadic { &self.args[..self.fixed_count] } else { &self.args }
+    }
+
+    /// Returns the arguments passed in the variadic part of a C-variadic call,
+    /// which is always empty for functions which aren't C-variadic.
+    pub fn variadic_args(&self) -> &[ArgAbi<'a, Ty>] {
+        assert!(self.fixed_count <= self.args.len());
+        if self.c_variadic { &self.args[self.fixed_count..] } else { &[] }
+    }
+
+    /// Returns `true` if `self` and `other` are lowered the same way: the same
+    /// calling convention and flags, and arguments and return values passed in the
+    /// same mode with the same size and alignment. The types themselves aren't
+    /// compared, so this can be used to share the backend function type of
+    /// functions with identical ABIs.
+    pub fn all_args_eq_abi(&self, other: &FnAbi<'a, Ty>) -> bool {
+        let arg_eq_abi = |a: &ArgAbi<'a, Ty>, b: &ArgAbi<'a, Ty>| {
+            a.mode == b.mode
+                && a.pad == b.pad
+                && a.layout.size == b.layout.size
+                && a.layout.align.abi == b.layout.align.abi
+        };
+        self.conv == other.conv
+            && self.c_variadic == other.c_variadic
+            && self.fixed_count == other.fixed_count
+            && self.can_unwind == other.can_unwind
+            && arg_eq_abi(&self.ret, &other.ret)
+            && self.args.len() == other.args.len()
+            && self.args.iter().zip(&other.args).all(|(a, b)| arg_eq_abi(a, b))
+    }
+
+    /// Returns a human-readable summary of how the arguments and the return value
+    /// are passed, one per line, e.g. for `#[rustc_abi]` and `-Zverbose` output.
+    pub fn pretty_print(&self) -> String {
+        let describe = |arg: &ArgAbi<'a, Ty>| {
+            format!(
+                "{} (size {}, align {})",
+                arg.mode.describe(),
+                arg.layout.size.bytes(),
+                arg.layout.align.abi.bytes()
+            )
+        };
+        let mut out = format!(
+            "conv: {:?}, c_variadic: {}, can_unwind: {}\n",
+            self.conv, self.c_variadic, self.can_unwind
+        );
+        for (i, arg) in self.args.iter().enumerate() {
+            out.push_str(&format!("arg {}: {}\n", i, describe(arg)));
+        }
+        out.push_str(&format!("ret: {}\n", describe(&self.ret)));
+        out
+    }
+
+    /// Calls `f` on the return value, then on each argument.
+    pub fn for_each_arg_mut(&mut self, mut f: impl FnMut(&mut ArgAbi<'a, Ty>)) {
+        f(&mut self.ret);
+        for arg in &mut self.args {
+            f(arg);
+        }
+    }
+
+    /// Checks that a function whose calling convention can never unwind
+    /// (see `Conv::disallows_unwinding`) isn't marked as `can_unwind`.
+    pub fn conv_requires_no_unwind(&self) -> Result<(), String> {
+        if self.can_unwind && self.conv.disallows_unwinding() {
+            Err(format!("functions with the `{:?}` calling convention cannot unwind", self.conv))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn adjust_for_foreign_abi<C>(
         &mut self,
         cx: &C,
@@ -655,6 +1236,28 @@ pub fn adjust_for_foreign_abi<C>(
         Ty: TyAbiInterface<'a, C> + Copy,
         C: HasDataLayout + HasTargetSpec,
     {
+        // Otherwise, the architecture's default ABI would be silently used instead.
+        // `stdcall`, `fastcall` and `vectorcall` on other architectures (`None`) are
+        // still accepted, and lowered as `C`: they only trigger the future-compat
+        // `unsupported_calling_conventions` lint, so rejecting them here would turn
+        // that lint into a hard error.
+        if cx.target_spec().is_abi_supported(abi) == Some(false) {
+            return Err(AdjustForForeignAbiError::UnsupportedAbiForArch {
+                arch: Symbol::intern(&cx.target_spec().arch),
+                abi,
+            });
+        }
+
+        let Some(arch) = ForeignAbiArch::from_target_arch(&cx.target_spec().arch) else {
+            let arch = Symbol::intern(&cx.target_spec().arch);
+            return Err(AdjustForForeignAbiError::Unsupported { arch, abi });
+        };
+
+        // Interrupt handlers and kernel entry points have no caller to unwind into.
+        if self.conv.disallows_unwinding() {
+            self.can_unwind = false;
+        }
+
         if abi == spec::abi::Abi::X86Interrupt {
             if let Some(arg) = self.args.first_mut() {
                 arg.make_indirect_byval();
@@ -662,8 +1265,8 @@ pub fn adjust_for_foreign_abi<C>(
             return Ok(());
         }
 
-        match &cx.target_spec().arch[..] {
-            "x86" => {
+        match arch {
+            ForeignAbiArch::X86 => {
                 let flavor = if let spec::abi::Abi::Fastcall { .. } = abi {
                     x86::Flavor::Fastcall
                 } else {
@@ -671,7 +1274,7 @@ pub fn adjust_for_foreign_abi<C>(
                 };
                 x86::compute_abi_info(cx, self, flavor);
             }
-            "x86_64" => match abi {
+            ForeignAbiArch::X86_64 => match abi {
                 spec::abi::Abi::SysV64 { .. } => x86_64::compute_abi_info(cx, self),
                 spec::abi::Abi::Win64 { .. } => x86_win64::compute_abi_info(self),
                 _ => {
@@ -682,40 +1285,93 @@ pub fn adjust_for_foreign_abi<C>(
                     }
                 }
             },
-            "aarch64" => aarch64::compute_abi_info(cx, self),
-            "amdgpu" => amdgpu::compute_abi_info(cx, self),
-            "arm" => arm::compute_abi_info(cx, self),
-            "avr" => avr::compute_abi_info(self),
-            "m68k" => m68k::compute_abi_info(self),
-            "mips" => mips::compute_abi_info(cx, self),
-            "mips64" => mips64::compute_abi_info(cx, self),
-            "powerpc" => powerpc::compute_abi_info(self),
-            "powerpc64" => powerpc64::compute_abi_info(cx, self),
-            "s390x" => s390x::compute_abi_info(cx, self),
-            "msp430" => msp430::compute_abi_info(self),
-            "sparc" => sparc::compute_abi_info(cx, self),
-            "sparc64" => sparc64::compute_abi_info(cx, self),
-            "nvptx" => nvptx::compute_abi_info(self),
-            "nvptx64" => nvptx64::compute_abi_info(self),
-            "hexagon" => hexagon::compute_abi_info(self),
-            "riscv32" | "riscv64" => riscv::compute_abi_info(cx, self),
-            "wasm32" | "wasm64" => {
+            ForeignAbiArch::Aarch64 => aarch64::compute_abi_info(cx, self),
+            ForeignAbiArch::AmdGpu => amdgpu::compute_abi_info(cx, self),
+            ForeignAbiArch::Arm => arm::compute_abi_info(cx, self),
+            ForeignAbiArch::Avr => avr::compute_abi_info(self),
+            ForeignAbiArch::M68k => m68k::compute_abi_info(self),
+            ForeignAbiArch::Mips => mips::compute_abi_info(cx, self),
+            ForeignAbiArch::Mips64 => mips64::compute_abi_info(cx, self),
+            ForeignAbiArch::PowerPc => powerpc::compute_abi_info(self),
+            ForeignAbiArch::PowerPc64 => powerpc64::compute_abi_info(cx, self),
+            ForeignAbiArch::S390x => s390x::compute_abi_info(cx, self),
+            ForeignAbiArch::Msp430 => msp430::compute_abi_info(self),
+            ForeignAbiArch::Sparc => sparc::compute_abi_info(cx, self),
+            ForeignAbiArch::Sparc64 => sparc64::compute_abi_info(cx, self),
+            ForeignAbiArch::Nvptx => nvptx::compute_abi_info(self),
+            ForeignAbiArch::Nvptx64 => nvptx64::compute_abi_info(self),
+            ForeignAbiArch::Hexagon => hexagon::compute_abi_info(self),
+            ForeignAbiArch::RiscV => riscv::compute_abi_info(cx, self),
+            ForeignAbiArch::Wasm => {
                 if cx.target_spec().adjust_abi(abi) == spec::abi::Abi::Wasm {
                     wasm::compute_wasm_abi_info(self)
                 } else {
                     wasm::compute_c_abi_info(cx, self)
                 }
             }
-            "asmjs" => wasm::compute_c_abi_info(cx, self),
-            "bpf" => bpf::compute_abi_info(self),
-            arch => {
-                return Err(AdjustForForeignAbiError::Unsupported {
-                    arch: Symbol::intern(arch),
-                    abi,
-                });
-            }
+            ForeignAbiArch::AsmJs => wasm::compute_c_abi_info(cx, self),
+            ForeignAbiArch::Bpf => bpf::compute_abi_info(self),
         }
 
         Ok(())
     }
+
+    /// Adjusts the argument and return modes computed by `ArgAbi::new` for the
+    /// Rust ABIs (`Rust`, `rust-call`, `rust-intrinsic` and `platform-intrinsic`),
+    /// which don't depend on the architecture: small aggregates are passed as an
+    /// integer, larger or unsized ones indirectly.
+    pub fn adjust_for_rust_abi<C>(&mut self, cx: &C, abi: spec::abi::Abi)
+    where
+        C: HasDataLayout + HasTargetSpec,
+    {
+        let fixup = |arg: &mut ArgAbi<'a, Ty>| {
+            if arg.is_ignore() {
+                return;
+            }
+
+            match arg.layout.abi {
+                Abi::Aggregate { .. } => {}
+
+                // This is a fun case! The gist of what this is doing is
+                // that we want callers and callees to always agree on the
+                // ABI of how they pass SIMD arguments. If we were to *not*
+                // make these arguments indirect then they'd be immediates
+                // in LLVM, which means that they'd used whatever the
+                // appropriate ABI is for the callee and the caller. That
+                // means, for example, if the caller doesn't have AVX
+                // enabled but the callee does, then passing an AVX argument
+                // across this boundary would cause corrupt data to show up.
+                //
+                // This problem is fixed by unconditionally passing SIMD
+                // arguments through memory between callers and callees
+                // which should get them all to agree on ABI regardless of
+                // target feature sets. Some more information about this
+                // issue can be found in #44367.
+                //
+                // Note that the platform intrinsic ABI is exempt here as
+                // that's how we connect up to LLVM and it's unstable
+                // anyway, we control all calls to it in libstd.
+                Abi::Vector { .. }
+                    if abi != spec::abi::Abi::PlatformIntrinsic
+                        && cx.target_spec().simd_types_indirect =>
+                {
+                    arg.make_indirect();
+                    return;
+                }
+
+                _ => return,
+            }
+
+            let size = arg.layout.size;
+            if arg.layout.is_unsized() || size > cx.data_layout().pointer_size {
+                arg.make_indirect();
+            } else {
+                // We want to pass small aggregates as immediates, but using
+                // a LLVM aggregate type for this leads to bad optimizations,
+                // so we pick an appropriately sized integer type instead.
+                arg.cast_to(Reg { kind: RegKind::Integer, size });
+            }
+        };
+        self.for_each_arg_mut(fixup);
+    }
 }
@@ -2,7 +2,7 @@
 // Alignment of 128 bit types is not currently handled, this will
 // need to be fixed when PowerPC vector support is added.
 
-use crate::abi::call::{ArgAbi, FnAbi, Reg, RegKind, Uniform};
+use crate::abi::call::{ArgAbi, ArgExtension, FnAbi, Reg, RegKind, Uniform};
 use crate::abi::{Endian, HasDataLayout, TyAbiInterface};
 use crate::spec::HasTargetSpec;
 
@@ -22,12 +22,13 @@ fn is_homogeneous_aggregate<'a, Ty, C>(
     Ty: TyAbiInterface<'a, C> + Copy,
     C: HasDataLayout,
 {
-    arg.layout.homogeneous_aggregate(cx).ok().and_then(|ha| ha.unit()).and_then(|unit| {
+    arg.layout.homogeneous_aggregate(cx).ok().and_then(|ha| {
+        let unit = ha.unit()?;
+        let count = ha.unit_count()?;
+
         // ELFv1 only passes one-member aggregates transparently.
         // ELFv2 passes up to eight uniquely addressable members.
-        if (abi == ELFv1 && arg.layout.size > unit.size)
-            || arg.layout.size > unit.size.checked_mul(8, cx).unwrap()
-        {
+        if (abi == ELFv1 && count > 1) || count > 8 {
             return None;
         }
 
@@ -37,7 +38,11 @@ fn is_homogeneous_aggregate<'a, Ty, C>(
             RegKind::Vector => arg.layout.size.bits() == 128,
         };
 
-        valid_unit.then_some(Uniform { unit, total: arg.layout.size })
+        valid_unit.then_some(Uniform {
+            unit,
+            total: arg.layout.size,
+            trailing_ext: ArgExtension::None,
+        })
     })
 }
 
@@ -77,7 +82,7 @@ fn classify_ret<'a, Ty, C>(cx: &C, ret: &mut ArgAbi<'a, Ty>, abi: ABI)
             Reg::i64()
         };
 
-        ret.cast_to(Uniform { unit, total: size });
+        ret.cast_to(Uniform { unit, total: size, trailing_ext: ArgExtension::None });
         return;
     }
 
@@ -111,7 +116,7 @@ fn classify_arg<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>, abi: ABI)
         (reg_i64, size.align_to(reg_i64.align(cx)))
     };
 
-    arg.cast_to(Uniform { unit, total });
+    arg.cast_to(Uniform { unit, total, trailing_ext: ArgExtension::None });
 }
 
 pub fn compute_abi_info<'a, Ty, C>(cx: &C, fn_abi: &mut FnAbi<'a, Ty>)
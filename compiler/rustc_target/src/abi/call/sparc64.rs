@@ -190,22 +190,20 @@ fn classify_arg<'a, Ty, C>(cx: &C, arg: &mut ArgAbi<'a, Ty>, in_registers_max: S
                     rest_size = rest_size - Reg::i32().size;
                 }
 
-                arg.cast_to(CastTarget {
-                    prefix: data.prefix,
-                    rest: Uniform { unit: Reg::i64(), total: rest_size },
-                    attrs: ArgAttributes {
-                        regular: data.arg_attribute,
-                        arg_ext: ArgExtension::None,
-                        pointee_size: Size::ZERO,
-                        pointee_align: None,
-                    },
-                });
+                let rest = Uniform {
+                    unit: Reg::i64(),
+                    total: rest_size,
+                    trailing_ext: ArgExtension::None,
+                };
+                let mut attrs = ArgAttributes::new();
+                attrs.set(data.arg_attribute);
+                arg.cast_to(CastTarget::prefixed(data.prefix, rest).with_attrs(attrs));
                 return;
             }
         }
     }
 
-    arg.cast_to(Uniform { unit: Reg::i64(), total });
+    arg.cast_to(Uniform { unit: Reg::i64(), total, trailing_ext: ArgExtension::None });
 }
 
 pub fn compute_abi_info<'a, Ty, C>(cx: &C, fn_abi: &mut FnAbi<'a, Ty>)
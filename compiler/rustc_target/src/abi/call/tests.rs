@@ -0,0 +1,1035 @@
+use super::*;
+use crate::abi::{Integer, LayoutS, Primitive, TargetDataLayout, VariantIdx, WrappingRange};
+use crate::spec::{Target, TargetTriple};
+use rustc_data_structures::intern::Interned;
+use rustc_index::vec::Idx;
+
+fn int_scalar(integer: Integer, signed: bool) -> abi::Scalar {
+    abi::Scalar::Initialized {
+        value: Primitive::Int(integer, signed),
+        valid_range: WrappingRange::full(integer.size()),
+    }
+}
+
+/// A scalar of type `value` for which every bit pattern is valid, e.g. an `f32`.
+fn scalar(dl: &TargetDataLayout, value: Primitive) -> abi::Scalar {
+    abi::Scalar::Initialized { value, valid_range: WrappingRange::full(value.size(dl)) }
+}
+
+fn scalar_layout<'a>(dl: &TargetDataLayout, value: Primitive) -> LayoutS<'a> {
+    LayoutS::scalar(dl, scalar(dl, value))
+}
+
+fn scalar_pair_layout<'a>(dl: &TargetDataLayout, a: abi::Scalar, b: abi::Scalar) -> LayoutS<'a> {
+    let b_offset = a.size(dl).align_to(b.align(dl).abi);
+    let align = a.align(dl).max(b.align(dl)).max(dl.aggregate_align);
+    LayoutS {
+        variants: abi::Variants::Single { index: VariantIdx::new(0) },
+        fields: FieldsShape::Arbitrary {
+            offsets: vec![Size::ZERO, b_offset],
+            memory_index: vec![0, 1],
+        },
+        abi: Abi::ScalarPair(a, b),
+        largest_niche: None,
+        align,
+        size: (b_offset + b.size(dl)).align_to(align.abi),
+    }
+}
+
+/// A stand-in for a type, which only knows the layouts of its fields.
+#[derive(Copy, Clone, Debug)]
+struct TestTy<'a> {
+    fields: &'a [TyAndLayout<'a, TestTy<'a>>],
+}
+
+impl<'a, C> TyAbiInterface<'a, C> for TestTy<'a> {
+    fn ty_and_layout_for_variant(
+        this: TyAndLayout<'a, Self>,
+        _: &C,
+        _: VariantIdx,
+    ) -> TyAndLayout<'a, Self> {
+        this
+    }
+
+    fn ty_and_layout_field(this: TyAndLayout<'a, Self>, _: &C, i: usize) -> TyAndLayout<'a, Self> {
+        this.ty.fields[i]
+    }
+
+    fn ty_and_layout_pointee_info_at(
+        _: TyAndLayout<'a, Self>,
+        _: &C,
+        _: Size,
+    ) -> Option<abi::PointeeInfo> {
+        None
+    }
+}
+
+/// A type with the given fields and layout, e.g. a scalar if `fields` is empty.
+fn ty_and_layout<'a>(
+    fields: &'a [TyAndLayout<'a, TestTy<'a>>],
+    layout: &'a LayoutS<'a>,
+) -> TyAndLayout<'a, TestTy<'a>> {
+    TyAndLayout { ty: TestTy { fields }, layout: abi::Layout(Interned::new_unchecked(layout)) }
+}
+
+fn arg_abi<'a>(dl: &TargetDataLayout, layout: &'a LayoutS<'a>) -> ArgAbi<'a, ()> {
+    let layout = TyAndLayout { ty: (), layout: abi::Layout(Interned::new_unchecked(layout)) };
+    ArgAbi::new(dl, layout, |_, _, _| ArgAttributes::new())
+}
+
+fn unit_layout<'a>(dl: &TargetDataLayout) -> LayoutS<'a> {
+    LayoutS {
+        variants: abi::Variants::Single { index: VariantIdx::new(0) },
+        fields: FieldsShape::Arbitrary { offsets: vec![], memory_index: vec![] },
+        abi: Abi::Aggregate { sized: true },
+        largest_niche: None,
+        align: dl.i8_align,
+        size: Size::ZERO,
+    }
+}
+
+/// The `FnAbi` of an `extern fn()` with the given calling convention.
+fn unit_fn_abi<'a>(
+    dl: &TargetDataLayout,
+    unit_layout: &'a LayoutS<'a>,
+    conv: Conv,
+) -> FnAbi<'a, TestTy<'a>> {
+    let mut ret = ArgAbi::new(dl, ty_and_layout(&[], unit_layout), |_, _, _| ArgAttributes::new());
+    ret.mode = PassMode::Ignore;
+    let mut fn_abi = FnAbi::new_internal(ret, vec![], conv);
+    fn_abi.can_unwind = false;
+    fn_abi
+}
+
+struct TestCx {
+    data_layout: TargetDataLayout,
+    target: Target,
+}
+
+impl TestCx {
+    fn new(triple: &str) -> Self {
+        let target = Target::expect_builtin(&TargetTriple::from_triple(triple));
+        TestCx { data_layout: TargetDataLayout::parse(&target).unwrap(), target }
+    }
+}
+
+impl HasDataLayout for TestCx {
+    fn data_layout(&self) -> &TargetDataLayout {
+        &self.data_layout
+    }
+}
+
+impl HasTargetSpec for TestCx {
+    fn target_spec(&self) -> &Target {
+        &self.target
+    }
+}
+
+/// Computes the `FnAbi` of an `extern "C" fn` taking `args` and returning `ret` on the
+/// target `triple`, so that the ABI of a target can be tested without going through codegen.
+fn foreign_fn_abi_for_test<'a>(
+    triple: &str,
+    args: &[TyAndLayout<'a, TestTy<'a>>],
+    ret: TyAndLayout<'a, TestTy<'a>>,
+) -> FnAbi<'a, TestTy<'a>> {
+    let cx = TestCx::new(triple);
+    let arg_abi = |layout| ArgAbi::new(&cx, layout, |_, _, _| ArgAttributes::new());
+    let mut fn_abi = FnAbi::new_internal(
+        arg_abi(ret),
+        args.iter().map(|&layout| arg_abi(layout)).collect(),
+        Conv::C,
+    );
+    fn_abi.adjust_for_foreign_abi(&cx, crate::spec::abi::Abi::C { unwind: false }).unwrap();
+    fn_abi
+}
+
+/// The layout of a `#[repr(C)]` struct with the given fields.
+fn struct_layout<'a>(fields: &[TyAndLayout<'a, TestTy<'a>>]) -> LayoutS<'a> {
+    let mut offsets = vec![];
+    let mut size = Size::ZERO;
+    let mut align = fields[0].align;
+    for field in fields {
+        let offset = size.align_to(field.align.abi);
+        offsets.push(offset);
+        size = offset + field.size;
+        align = align.max(field.align);
+    }
+    LayoutS {
+        variants: abi::Variants::Single { index: VariantIdx::new(0) },
+        fields: FieldsShape::Arbitrary {
+            memory_index: (0..fields.len() as u32).collect(),
+            offsets,
+        },
+        abi: Abi::Aggregate { sized: true },
+        largest_niche: None,
+        align,
+        size: size.align_to(align.abi),
+    }
+}
+
+/// The layout of an array of `count` elements of layout `elem`.
+fn array_layout<'a>(elem: &LayoutS<'a>, count: u64) -> LayoutS<'a> {
+    LayoutS {
+        variants: abi::Variants::Single { index: VariantIdx::new(0) },
+        fields: FieldsShape::Array { stride: elem.size, count },
+        abi: Abi::Aggregate { sized: true },
+        largest_niche: None,
+        align: elem.align,
+        size: elem.size * count,
+    }
+}
+
+#[test]
+fn arg_attributes_remove_and_toggle() {
+    let mut attrs = ArgAttributes::new();
+    attrs.set(ArgAttribute::NonNull).set(ArgAttribute::NoAlias);
+    assert!(attrs.contains(ArgAttribute::NonNull));
+
+    attrs.remove(ArgAttribute::NonNull);
+    assert!(!attrs.contains(ArgAttribute::NonNull));
+    assert!(attrs.contains(ArgAttribute::NoAlias));
+    // Removing a flag which isn't set is a no-op.
+    attrs.remove(ArgAttribute::NonNull);
+    assert_eq!(attrs.regular, ArgAttribute::NoAlias);
+
+    attrs.toggle(ArgAttribute::NoAlias).toggle(ArgAttribute::ReadOnly);
+    assert_eq!(attrs.regular, ArgAttribute::ReadOnly);
+    attrs.toggle(ArgAttribute::ReadOnly);
+    assert_eq!(attrs, ArgAttributes::new());
+}
+
+#[test]
+fn arg_attributes_ext_or_keep() {
+    let mut attrs = ArgAttributes::new();
+    attrs.ext(ArgExtension::Sext);
+
+    // The conflicting extension is ignored instead of panicking.
+    attrs.ext_or_keep(ArgExtension::Zext);
+    assert_eq!(attrs.arg_ext, ArgExtension::Sext);
+
+    attrs.clear_ext();
+    assert_eq!(attrs.arg_ext, ArgExtension::None);
+    attrs.ext_or_keep(ArgExtension::Zext);
+    assert_eq!(attrs.arg_ext, ArgExtension::Zext);
+}
+
+#[test]
+fn arg_attributes_merge() {
+    let mut attrs = ArgAttributes::new();
+    attrs.set(ArgAttribute::NonNull).ext(ArgExtension::Zext);
+    attrs.pointee_size = Size::from_bytes(4);
+
+    let mut other = ArgAttributes::new();
+    other.set(ArgAttribute::NoAlias);
+    other.pointee_size = Size::from_bytes(8);
+    other.pointee_align = Some(Align::from_bytes(8).unwrap());
+
+    attrs.merge(&other);
+    assert!(attrs.contains(ArgAttribute::NonNull) && attrs.contains(ArgAttribute::NoAlias));
+    assert_eq!(attrs.pointee_size, Size::from_bytes(8));
+    assert_eq!(attrs.pointee_align, Some(Align::from_bytes(8).unwrap()));
+    // `other` has no extension, so `self`'s is kept.
+    assert_eq!(attrs.arg_ext, ArgExtension::Zext);
+}
+
+#[test]
+#[should_panic]
+fn arg_attributes_merge_conflicting_ext() {
+    let mut attrs = ArgAttributes::new();
+    attrs.ext(ArgExtension::Zext);
+    let mut other = ArgAttributes::new();
+    other.ext(ArgExtension::Sext);
+    attrs.merge(&other);
+}
+
+#[test]
+fn reg_from_integer_size() {
+    for bits in [8, 16, 32, 64, 128] {
+        let reg = Reg::from_integer_size(Size::from_bits(bits)).unwrap();
+        assert_eq!(reg, Reg { kind: RegKind::Integer, size: Size::from_bits(bits) });
+    }
+    for bits in [0, 17, 65, 129] {
+        assert_eq!(Reg::from_integer_size(Size::from_bits(bits)), None);
+    }
+}
+
+#[test]
+fn reg_from_float_size() {
+    assert_eq!(Reg::from_float_size(Size::from_bits(32)), Some(Reg::f32()));
+    assert_eq!(Reg::from_float_size(Size::from_bits(64)), Some(Reg::f64()));
+    for bits in [0, 16, 17, 65, 128, 129] {
+        assert_eq!(Reg::from_float_size(Size::from_bits(bits)), None);
+    }
+}
+
+#[test]
+fn reg_kind_predicates() {
+    for reg in [Reg::i8(), Reg::i16(), Reg::i32(), Reg::i64(), Reg::i128()] {
+        assert!(reg.is_integer() && !reg.is_float() && !reg.is_vector());
+    }
+    for reg in [Reg::f32(), Reg::f64()] {
+        assert!(reg.is_float() && !reg.is_integer() && !reg.is_vector());
+    }
+    let vector = Reg { kind: RegKind::Vector, size: Size::from_bits(128) };
+    assert!(vector.is_vector() && !vector.is_integer() && !vector.is_float());
+}
+
+#[test]
+fn reg_abi_compatible() {
+    assert!(Reg::i32().same_size_as(&Reg::f32()));
+    assert!(Reg::i32().abi_compatible(&Reg::f32()));
+    assert!(Reg::f64().abi_compatible(&Reg::i64()));
+    assert_ne!(Reg::i32(), Reg::f32());
+
+    assert!(!Reg::i32().same_size_as(&Reg::i64()));
+    assert!(!Reg::i32().abi_compatible(&Reg::i64()));
+
+    let vector = Reg { kind: RegKind::Vector, size: Size::from_bits(64) };
+    assert!(vector.abi_compatible(&vector));
+    assert!(!vector.abi_compatible(&Reg::i64()));
+}
+
+#[test]
+fn vector_reg_align() {
+    // The default data layout only specifies the alignment of 64 and 128-bit vectors.
+    let dl = TargetDataLayout::default();
+    let vector = |bits: u64| Reg { kind: RegKind::Vector, size: Size::from_bits(bits) };
+    assert_eq!(vector(128).align(&dl), Align::from_bytes(16).unwrap());
+    assert_eq!(vector(512).align(&dl), Align::from_bytes(64).unwrap());
+    assert_eq!(vector(384).align(&dl), Align::from_bytes(64).unwrap());
+}
+
+#[test]
+fn extend_integer_width_of_scalar_pair() {
+    let dl = TargetDataLayout::default();
+    let layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I8, true), int_scalar(Integer::I8, false));
+    let mut arg = arg_abi(&dl, &layout);
+
+    arg.extend_integer_width_to(32);
+    match arg.mode {
+        PassMode::Pair(a, b) => {
+            assert_eq!(a.arg_ext, ArgExtension::Sext);
+            assert_eq!(b.arg_ext, ArgExtension::Zext);
+        }
+        _ => panic!("expected a pair, found {:?}", arg.mode),
+    }
+}
+
+#[test]
+fn pass_mode_predicates() {
+    let dl = TargetDataLayout::default();
+    let layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I32, true));
+    let mut arg = arg_abi(&dl, &layout);
+    assert!(arg.is_pair());
+    assert!(!arg.is_direct() && !arg.is_cast() && !arg.is_indirect() && !arg.is_ignore());
+
+    arg.cast_to(Reg::i64());
+    assert!(arg.is_cast());
+    assert!(!arg.is_direct() && !arg.is_pair());
+
+    arg.mode = PassMode::Direct(ArgAttributes::new());
+    assert!(arg.is_direct());
+    assert!(!arg.is_pair() && !arg.is_cast());
+
+    arg.make_indirect();
+    assert!(arg.is_indirect());
+    assert!(!arg.is_direct() && !arg.is_pair() && !arg.is_cast());
+}
+
+#[test]
+fn pass_mode_describe() {
+    let attrs = ArgAttributes::new();
+    assert_eq!(PassMode::Ignore.describe(), "ignore");
+    assert_eq!(PassMode::Direct(attrs).describe(), "direct");
+    assert_eq!(PassMode::Pair(attrs, attrs).describe(), "pair");
+
+    let cast = CastTarget::prefixed(
+        [Some(Reg::i32()), Some(Reg::f32()), None, None, None, None, None, None],
+        Uniform { unit: Reg::i64(), total: Size::from_bytes(20), trailing_ext: ArgExtension::None },
+    );
+    assert_eq!(PassMode::Cast(cast).describe(), "cast(prefix=2,rest=i64x3)");
+    assert_eq!(
+        PassMode::Cast(CastTarget::from(Reg::f64())).describe(),
+        "cast(prefix=0,rest=f64x1)"
+    );
+
+    let indirect = PassMode::Indirect { attrs, extra_attrs: None, on_stack: false };
+    assert_eq!(indirect.describe(), "indirect");
+    let byval = PassMode::Indirect { attrs, extra_attrs: None, on_stack: true };
+    assert_eq!(byval.describe(), "indirect(byval)");
+    let unsized_indirect = PassMode::Indirect { attrs, extra_attrs: Some(attrs), on_stack: false };
+    assert_eq!(unsized_indirect.describe(), "indirect(ptr+meta)");
+}
+
+#[test]
+fn cast_to_inreg() {
+    let dl = TargetDataLayout::default();
+    let layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I32, true));
+    let mut arg = arg_abi(&dl, &layout);
+    let mut inreg_arg = arg_abi(&dl, &layout);
+
+    arg.cast_to(Reg::i64());
+    inreg_arg.cast_to_inreg(Reg::i64());
+    match (arg.mode, inreg_arg.mode) {
+        (PassMode::Cast(cast), PassMode::Cast(inreg_cast)) => {
+            assert!(!cast.attrs.contains(ArgAttribute::InReg));
+            assert!(inreg_cast.attrs.contains(ArgAttribute::InReg));
+            assert_eq!(CastTarget { attrs: ArgAttributes::new(), ..inreg_cast }, cast);
+        }
+        modes => panic!("expected two casts, found {:?}", modes),
+    }
+}
+
+#[test]
+fn make_ignore_zst() {
+    let dl = TargetDataLayout::default();
+    let unit_layout = unit_layout(&dl);
+    let mut arg = arg_abi(&dl, &unit_layout);
+    arg.make_ignore();
+    assert!(arg.is_ignore());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn make_ignore_scalar() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    arg_abi(&dl, &i32_layout).make_ignore();
+}
+
+#[test]
+fn indirect_is_noalias() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let pair_layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I64, true));
+
+    let mut arg = arg_abi(&dl, &pair_layout);
+    arg.make_indirect_byval();
+    assert_eq!(arg.indirect_is_noalias(), Some(true));
+
+    if let PassMode::Indirect { attrs, .. } = &mut arg.mode {
+        attrs.remove(ArgAttribute::NoAlias);
+    }
+    assert_eq!(arg.indirect_is_noalias(), Some(false));
+
+    assert_eq!(arg_abi(&dl, &i32_layout).indirect_is_noalias(), None);
+}
+
+#[test]
+fn scalar_pair_element_offset() {
+    let cx = TestCx::new("x86_64-unknown-linux-gnu");
+    let dl = cx.data_layout();
+
+    // `(u8, u64)`
+    let pair_layout =
+        scalar_pair_layout(dl, int_scalar(Integer::I8, false), int_scalar(Integer::I64, false));
+    let arg = arg_abi(dl, &pair_layout);
+    assert_eq!(arg.scalar_pair_element_offset(&cx, 0), Size::ZERO);
+    assert_eq!(arg.scalar_pair_element_offset(&cx, 1), Size::from_bytes(8));
+}
+
+#[test]
+fn cast_target_register_counts() {
+    let dl = TargetDataLayout::default();
+
+    let rest_only = CastTarget::from(Uniform {
+        unit: Reg::i64(),
+        total: Size::from_bytes(24),
+        trailing_ext: ArgExtension::None,
+    });
+    assert_eq!(rest_only.prefix_count(), 0);
+    assert_eq!(rest_only.rest_count(&dl), 3);
+    assert_eq!(rest_only.size(&dl), Reg::i64().size * 3);
+
+    let prefix_only = CastTarget::prefixed(
+        [Some(Reg::i32()), Some(Reg::f32()), None, None, None, None, None, None],
+        Uniform { unit: Reg::i32(), total: Size::ZERO, trailing_ext: ArgExtension::None },
+    );
+    assert_eq!(prefix_only.prefix_count(), 2);
+    assert_eq!(prefix_only.rest_count(&dl), 0);
+    assert_eq!(prefix_only.size(&dl), Reg::i32().size + Reg::f32().size);
+
+    let mixed = CastTarget::pair(Reg::f64(), Reg::i64());
+    assert_eq!(mixed.prefix_count(), 1);
+    assert_eq!(mixed.rest_count(&dl), 1);
+    assert_eq!(mixed.size(&dl), Reg::f64().size + Reg::i64().size);
+
+    // A shorter trailing integer still takes up a whole register.
+    let trailing = CastTarget::from(Uniform {
+        unit: Reg::i64(),
+        total: Size::from_bytes(20),
+        trailing_ext: ArgExtension::None,
+    });
+    assert_eq!(trailing.rest_count(&dl), 3);
+    assert_eq!(trailing.size(&dl), Size::from_bytes(20));
+    assert!(Reg::i64().size * trailing.rest_count(&dl) >= trailing.size(&dl));
+}
+
+#[test]
+fn reg_repeated() {
+    let dl = TargetDataLayout::default();
+    let cast = Reg::i64().repeated(3);
+    assert_eq!(cast.prefix_count(), 0);
+    assert_eq!(cast.rest.unit, Reg::i64());
+    assert_eq!(cast.rest.total, Size::from_bytes(24));
+    assert_eq!(cast.rest_count(&dl), 3);
+    assert_eq!(cast.size(&dl), Size::from_bytes(24));
+
+    assert_eq!(Reg::f32().repeated(1), CastTarget::from(Reg::f32()));
+}
+
+#[test]
+fn cast_target_from_registers() {
+    let dl = TargetDataLayout::default();
+
+    let cast = CastTarget::from_registers(&[Reg::i64(), Reg::f64()]);
+    assert_eq!(cast.prefix[..3], [Some(Reg::i64()), Some(Reg::f64()), None]);
+    assert_eq!(cast.rest.total, Size::ZERO);
+    assert_eq!(cast.size(&dl), Size::from_bytes(16));
+
+    let cast = CastTarget::from_registers(&[Reg::i32(); 8]);
+    assert_eq!(cast.prefix, [Some(Reg::i32()); 8]);
+    assert_eq!(cast.rest_count(&dl), 0);
+    assert_eq!(cast.size(&dl), Size::from_bytes(32));
+
+    // The two registers that don't fit in the prefix are folded into `rest`.
+    let mut regs = [Reg::i32(); 10];
+    regs[8] = Reg::i64();
+    regs[9] = Reg::i64();
+    let cast = CastTarget::from_registers(&regs);
+    assert_eq!(cast.prefix, [Some(Reg::i32()); 8]);
+    assert_eq!(cast.rest.unit, Reg::i64());
+    assert_eq!(cast.rest.total, Size::from_bytes(16));
+    assert_eq!(cast.rest_count(&dl), 2);
+    assert_eq!(cast.size(&dl), Size::from_bytes(48));
+}
+
+#[test]
+fn cast_target_size_align() {
+    let dl = TargetDataLayout::default();
+
+    // An `i32` and an `f64` in the prefix, and two `i64`s in `rest`.
+    let mut cast = CastTarget::from(Reg::i64());
+    cast.prefix[0] = Some(Reg::i32());
+    cast.prefix[1] = Some(Reg::f64());
+    cast.rest.total = Size::from_bytes(16);
+
+    let (size, align) = cast.size_align(&dl);
+    assert_eq!(size, cast.size(&dl));
+    assert_eq!(align, cast.align(&dl));
+    assert_eq!(size, Size::from_bytes(28));
+    assert_eq!(align, dl.f64_align.abi);
+}
+
+#[test]
+#[should_panic]
+fn cast_target_from_mixed_rest_registers() {
+    let mut regs = [Reg::i32(); 10];
+    regs[9] = Reg::f32();
+    CastTarget::from_registers(&regs);
+}
+
+#[test]
+fn uniform_with_sign_extended_trailing_element() {
+    let dl = TargetDataLayout::default();
+    // `{ i64, i64, i32 }`, with the trailing `i32` sign-extended.
+    let uniform =
+        Uniform { unit: Reg::i64(), total: Size::from_bytes(20), trailing_ext: ArgExtension::Sext };
+    assert_eq!(uniform.align(&dl), Reg::i64().align(&dl));
+
+    assert_eq!(CastTarget::from(uniform).size(&dl), Size::from_bytes(20));
+}
+
+#[test]
+fn fixed_and_variadic_args() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let i64_layout = LayoutS::scalar(&dl, int_scalar(Integer::I64, true));
+
+    // `extern "C" fn(i32, i32, ...)` called with an extra `i64`.
+    let mut fn_abi = FnAbi {
+        args: vec![arg_abi(&dl, &i32_layout), arg_abi(&dl, &i32_layout), arg_abi(&dl, &i64_layout)],
+        ret: arg_abi(&dl, &i32_layout),
+        c_variadic: true,
+        fixed_count: 2,
+        conv: Conv::C,
+        can_unwind: false,
+    };
+    assert_eq!(fn_abi.fixed_args(), &fn_abi.args[..2]);
+    assert_eq!(fn_abi.variadic_args(), &fn_abi.args[2..]);
+
+    fn_abi.args.pop();
+    fn_abi.c_variadic = false;
+    assert_eq!(fn_abi.fixed_args().len(), 2);
+    assert!(fn_abi.variadic_args().is_empty());
+}
+
+#[test]
+fn homogeneous_aggregate_of_array() {
+    let dl = TargetDataLayout::default();
+    let f32_layout = scalar_layout(&dl, Primitive::F32);
+
+    // `[f32; 5]`
+    let fields = [ty_and_layout(&[], &f32_layout)];
+    let array_layout = array_layout(&f32_layout, 5);
+    let array = ty_and_layout(&fields, &array_layout);
+
+    let ha = array.homogeneous_aggregate(&dl).unwrap();
+    assert!(matches!(
+        ha,
+        HomogeneousAggregate::Homogeneous { unit, total }
+            if unit == Reg::f32() && total == Size::from_bytes(20)
+    ));
+    assert_eq!(ha.unit(), Some(Reg::f32()));
+    assert_eq!(ha.unit_count(), Some(5));
+}
+
+#[test]
+fn homogeneous_aggregate_no_data_or_uninhabited() {
+    let dl = TargetDataLayout::default();
+
+    // `()` and `struct Empty;` have the same layout.
+    let empty_layout = unit_layout(&dl);
+    let empty = ty_and_layout(&[], &empty_layout);
+    assert!(matches!(empty.homogeneous_aggregate(&dl), Ok(HomogeneousAggregate::NoData)));
+
+    // `enum Void {}`
+    let void_layout = LayoutS { abi: Abi::Uninhabited, ..unit_layout(&dl) };
+    let void = ty_and_layout(&[], &void_layout);
+    assert!(void.homogeneous_aggregate(&dl).unwrap_err().uninhabited);
+
+    // `struct { a: f32, b: i32 }`
+    let f32_layout = scalar_layout(&dl, Primitive::F32);
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let fields = [ty_and_layout(&[], &f32_layout), ty_and_layout(&[], &i32_layout)];
+    let mixed_layout = struct_layout(&fields);
+    let mixed = ty_and_layout(&fields, &mixed_layout);
+    assert!(!mixed.homogeneous_aggregate(&dl).unwrap_err().uninhabited);
+}
+
+#[test]
+fn homogeneous_aggregate_detailed_reports_unions() {
+    let dl = TargetDataLayout::default();
+    let f32_layout = scalar_layout(&dl, Primitive::F32);
+    let f32_field = ty_and_layout(&[], &f32_layout);
+
+    // `union { a: f32, b: f32 }`
+    let union_layout = LayoutS {
+        variants: abi::Variants::Single { index: VariantIdx::new(0) },
+        fields: FieldsShape::Union(std::num::NonZeroUsize::new(2).unwrap()),
+        abi: Abi::Aggregate { sized: true },
+        largest_niche: None,
+        align: f32_layout.align,
+        size: Size::from_bytes(4),
+    };
+    let union_fields = [f32_field, f32_field];
+    let union = ty_and_layout(&union_fields, &union_layout);
+
+    let (ha, was_union) = union.homogeneous_aggregate_detailed(&dl).unwrap();
+    assert!(was_union);
+    assert_eq!(ha.unit(), Some(Reg::f32()));
+    assert_eq!(ha.unit_count(), Some(1));
+
+    // `[union { a: f32, b: f32 }; 2]` still reports the nested union.
+    let array_fields = [union];
+    let array_layout = array_layout(&union_layout, 2);
+    let array = ty_and_layout(&array_fields, &array_layout);
+    let (ha, was_union) = array.homogeneous_aggregate_detailed(&dl).unwrap();
+    assert!(was_union);
+    assert_eq!(ha.unit_count(), Some(2));
+
+    // `struct { a: f32, b: f32 }` has no union.
+    let struct_layout = struct_layout(&union_fields);
+    let strukt = ty_and_layout(&union_fields, &struct_layout);
+    let (ha, was_union) = strukt.homogeneous_aggregate_detailed(&dl).unwrap();
+    assert!(!was_union);
+    assert_eq!(ha.unit_count(), Some(2));
+}
+
+#[test]
+fn conv_classification() {
+    let all = [
+        Conv::C,
+        Conv::Rust,
+        Conv::ArmAapcs,
+        Conv::CCmseNonSecureCall,
+        Conv::Msp430Intr,
+        Conv::PtxKernel,
+        Conv::X86Fastcall,
+        Conv::X86Intr,
+        Conv::X86Stdcall,
+        Conv::X86ThisCall,
+        Conv::X86VectorCall,
+        Conv::X86_64SysV,
+        Conv::X86_64Win64,
+        Conv::AmdGpuKernel,
+        Conv::AvrInterrupt,
+        Conv::AvrNonBlockingInterrupt,
+    ];
+    for conv in all {
+        // `(is_interrupt, disallows_unwinding)`; new conventions must be added
+        // both here and to the list above.
+        let expected = match conv {
+            Conv::Msp430Intr
+            | Conv::X86Intr
+            | Conv::AvrInterrupt
+            | Conv::AvrNonBlockingInterrupt => (true, true),
+            Conv::CCmseNonSecureCall | Conv::PtxKernel | Conv::AmdGpuKernel => (false, true),
+            Conv::C
+            | Conv::Rust
+            | Conv::ArmAapcs
+            | Conv::X86Fastcall
+            | Conv::X86Stdcall
+            | Conv::X86ThisCall
+            | Conv::X86VectorCall
+            | Conv::X86_64SysV
+            | Conv::X86_64Win64 => (false, false),
+        };
+        assert_eq!((conv.is_interrupt(), conv.disallows_unwinding()), expected, "{:?}", conv);
+    }
+}
+
+#[test]
+fn fn_abi_pretty_print() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let pair_layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I64, true));
+    let mut indirect = arg_abi(&dl, &pair_layout);
+    indirect.make_indirect();
+
+    // `extern "C" fn(i32, (i32, i64), (i32, i64)) -> i32`
+    let fn_abi = FnAbi::new_internal(
+        arg_abi(&dl, &i32_layout),
+        vec![arg_abi(&dl, &i32_layout), arg_abi(&dl, &pair_layout), indirect],
+        Conv::C,
+    );
+    assert_eq!(
+        fn_abi.pretty_print(),
+        "conv: C, c_variadic: false, can_unwind: true\n\
+         arg 0: direct (size 4, align 4)\n\
+         arg 1: pair (size 12, align 4)\n\
+         arg 2: indirect (size 12, align 4)\n\
+         ret: direct (size 4, align 4)\n"
+    );
+}
+
+#[test]
+fn fn_abi_all_args_eq_abi() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let pair_layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I64, true));
+
+    // `extern "C" fn(i32, (i32, i64)) -> i32`
+    let new_fn_abi = || {
+        FnAbi::new_internal(
+            arg_abi(&dl, &i32_layout),
+            vec![arg_abi(&dl, &i32_layout), arg_abi(&dl, &pair_layout)],
+            Conv::C,
+        )
+    };
+    let fn_abi = new_fn_abi();
+    assert!(fn_abi.all_args_eq_abi(&new_fn_abi()));
+
+    let mut nounwind = new_fn_abi();
+    nounwind.can_unwind = false;
+    assert!(!fn_abi.all_args_eq_abi(&nounwind));
+
+    let mut indirect = new_fn_abi();
+    indirect.args[1].make_indirect();
+    assert!(!fn_abi.all_args_eq_abi(&indirect));
+}
+
+#[test]
+fn for_each_arg_mut_visits_ret_and_args() {
+    let dl = TargetDataLayout::default();
+    let i32_layout = LayoutS::scalar(&dl, int_scalar(Integer::I32, true));
+    let pair_layout =
+        scalar_pair_layout(&dl, int_scalar(Integer::I32, true), int_scalar(Integer::I32, true));
+    let mut fn_abi = FnAbi::new_internal(
+        arg_abi(&dl, &i32_layout),
+        vec![arg_abi(&dl, &i32_layout), arg_abi(&dl, &pair_layout)],
+        Conv::C,
+    );
+
+    let mut visited = 0;
+    fn_abi.for_each_arg_mut(|arg| {
+        arg.make_indirect();
+        visited += 1;
+    });
+    assert_eq!(visited, 3);
+    assert!(fn_abi.ret.is_indirect());
+    assert!(fn_abi.args.iter().all(|arg| arg.is_indirect()));
+}
+
+#[test]
+fn conv_tiers() {
+    for conv in [Conv::C, Conv::Rust] {
+        assert!(conv.is_general() && !conv.is_target_specific());
+    }
+    for conv in [Conv::ArmAapcs, Conv::X86_64SysV, Conv::PtxKernel, Conv::AvrInterrupt] {
+        assert!(conv.is_target_specific() && !conv.is_general());
+    }
+}
+
+#[test]
+fn conv_supported_on_arch() {
+    assert!(Conv::C.supported_on_arch("x86_64"));
+    assert!(Conv::C.supported_on_arch("riscv64"));
+    assert!(!Conv::C.supported_on_arch("spirv"));
+
+    assert!(Conv::X86VectorCall.supported_on_arch("x86"));
+    assert!(Conv::X86VectorCall.supported_on_arch("x86_64"));
+    assert!(!Conv::X86VectorCall.supported_on_arch("aarch64"));
+    assert!(!Conv::X86ThisCall.supported_on_arch("x86_64"));
+    assert!(Conv::CCmseNonSecureCall.supported_on_arch("aarch64"));
+    assert!(Conv::AvrInterrupt.supported_on_arch("avr"));
+    assert!(!Conv::PtxKernel.supported_on_arch("nvptx"));
+    assert!(Conv::AmdGpuKernel.supported_on_arch("amdgpu"));
+    assert!(!Conv::AmdGpuKernel.supported_on_arch("amdgcn"));
+}
+
+#[test]
+fn conv_supported_on_arch_agrees_with_target() {
+    use crate::spec::abi::Abi as SpecAbi;
+    // Only the ABIs with a calling convention of their own, e.g. not `extern "wasm"`,
+    // which is lowered as `Conv::C`.
+    let abis = [
+        (SpecAbi::Rust, Conv::Rust),
+        (SpecAbi::C { unwind: false }, Conv::C),
+        (SpecAbi::Cdecl { unwind: false }, Conv::C),
+        (SpecAbi::Stdcall { unwind: false }, Conv::X86Stdcall),
+        (SpecAbi::Fastcall { unwind: false }, Conv::X86Fastcall),
+        (SpecAbi::Vectorcall { unwind: false }, Conv::X86VectorCall),
+        (SpecAbi::Thiscall { unwind: false }, Conv::X86ThisCall),
+        (SpecAbi::Aapcs { unwind: false }, Conv::ArmAapcs),
+        (SpecAbi::Win64 { unwind: false }, Conv::X86_64Win64),
+        (SpecAbi::SysV64 { unwind: false }, Conv::X86_64SysV),
+        (SpecAbi::PtxKernel, Conv::PtxKernel),
+        (SpecAbi::Msp430Interrupt, Conv::Msp430Intr),
+        (SpecAbi::X86Interrupt, Conv::X86Intr),
+        (SpecAbi::AmdGpuKernel, Conv::AmdGpuKernel),
+        (SpecAbi::AvrInterrupt, Conv::AvrInterrupt),
+        (SpecAbi::AvrNonBlockingInterrupt, Conv::AvrNonBlockingInterrupt),
+        (SpecAbi::CCmseNonSecureCall, Conv::CCmseNonSecureCall),
+    ];
+    for triple in crate::spec::TARGETS {
+        let target = Target::expect_builtin(&TargetTriple::from_triple(triple));
+        for (abi, conv) in abis {
+            // An ABI the target turns into another one, e.g. `stdcall` into `C` on
+            // x86_64, isn't lowered with its own calling convention.
+            if target.adjust_abi(abi) != abi {
+                continue;
+            }
+            if let Some(supported) = target.is_abi_supported(abi) {
+                assert_eq!(
+                    conv.supported_on_arch(&target.arch),
+                    supported,
+                    "`extern {}` on {}",
+                    abi,
+                    triple
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn interrupt_conv_cannot_unwind() {
+    let dl = TargetDataLayout::default();
+    let unit_layout = unit_layout(&dl);
+
+    // `extern "x86-interrupt" fn()`
+    let mut fn_abi = unit_fn_abi(&dl, &unit_layout, Conv::X86Intr);
+    assert_eq!(fn_abi.conv_requires_no_unwind(), Ok(()));
+
+    fn_abi.can_unwind = true;
+    assert!(fn_abi.conv_requires_no_unwind().is_err());
+
+    fn_abi.conv = Conv::C;
+    assert_eq!(fn_abi.conv_requires_no_unwind(), Ok(()));
+}
+
+#[test]
+fn adjust_for_unsupported_foreign_abi() {
+    use crate::spec::abi::Abi as SpecAbi;
+
+    rustc_span::create_default_session_globals_then(|| {
+        let cx = TestCx::new("x86_64-unknown-linux-gnu");
+        let unit_layout = unit_layout(cx.data_layout());
+
+        // `thiscall` only exists on 32-bit x86.
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::X86ThisCall);
+        assert!(matches!(
+            fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::Thiscall { unwind: false }),
+            Err(AdjustForForeignAbiError::UnsupportedAbiForArch { arch, .. })
+                if arch == Symbol::intern("x86_64")
+        ));
+
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::C);
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::C { unwind: false }).is_ok());
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::X86_64SysV);
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::SysV64 { unwind: false }).is_ok());
+
+        // Windows accepts `vectorcall` on every architecture, using the C ABI instead.
+        let cx = TestCx::new("aarch64-pc-windows-msvc");
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::C);
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::Vectorcall { unwind: false }).is_ok());
+
+        // Elsewhere, `vectorcall` on aarch64 is only linted against, so it is still
+        // lowered as C, while `thiscall` is rejected.
+        let cx = TestCx::new("aarch64-unknown-linux-gnu");
+        assert_eq!(cx.target.is_abi_supported(SpecAbi::Vectorcall { unwind: false }), None);
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::C);
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::Vectorcall { unwind: false }).is_ok());
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::X86ThisCall);
+        assert!(matches!(
+            fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::Thiscall { unwind: false }),
+            Err(AdjustForForeignAbiError::UnsupportedAbiForArch { arch, .. })
+                if arch == Symbol::intern("aarch64")
+        ));
+    });
+}
+
+#[test]
+fn adjust_for_interrupt_abi_disables_unwinding() {
+    use crate::spec::abi::Abi as SpecAbi;
+
+    rustc_span::create_default_session_globals_then(|| {
+        let cx = TestCx::new("avr-unknown-gnu-atmega328");
+        let unit_layout = unit_layout(cx.data_layout());
+
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::AvrInterrupt);
+        fn_abi.can_unwind = true;
+        fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::AvrInterrupt).unwrap();
+        assert!(!fn_abi.can_unwind);
+        assert_eq!(fn_abi.conv_requires_no_unwind(), Ok(()));
+
+        // Other conventions keep whatever `can_unwind` was computed from the ABI.
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::C);
+        fn_abi.can_unwind = true;
+        fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::C { unwind: true }).unwrap();
+        assert!(fn_abi.can_unwind);
+
+        // A failed adjustment leaves `can_unwind` as it was.
+        let mut cx = cx;
+        cx.target.arch = "spirv".into();
+        let mut fn_abi = unit_fn_abi(cx.data_layout(), &unit_layout, Conv::AvrInterrupt);
+        fn_abi.can_unwind = true;
+        assert!(matches!(
+            fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::C { unwind: false }),
+            Err(AdjustForForeignAbiError::Unsupported { .. })
+        ));
+        assert!(fn_abi.can_unwind);
+    });
+}
+
+#[test]
+fn wasm_abi_returns_scalar_pair_directly() {
+    use crate::spec::abi::Abi as SpecAbi;
+
+    rustc_span::create_default_session_globals_then(|| {
+        let cx = TestCx::new("wasm32-unknown-unknown");
+        let dl = cx.data_layout();
+        let i32_layout = LayoutS::scalar(dl, int_scalar(Integer::I32, true));
+        let f32_layout = scalar_layout(dl, Primitive::F32);
+        let fields = [ty_and_layout(&[], &i32_layout), ty_and_layout(&[], &f32_layout)];
+
+        // `extern "wasm" fn() -> (i32, f32)`
+        let pair_layout =
+            scalar_pair_layout(dl, int_scalar(Integer::I32, true), scalar(dl, Primitive::F32));
+        let ret = ty_and_layout(&fields, &pair_layout);
+        let new_fn_abi = || {
+            FnAbi::new_internal(
+                ArgAbi::new(dl, ret, |_, _, _| ArgAttributes::new()),
+                vec![],
+                Conv::C,
+            )
+        };
+
+        // The pair is returned as such, in two values instead of through memory.
+        let mut fn_abi = new_fn_abi();
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::Wasm).is_ok());
+        assert!(matches!(fn_abi.ret.mode, PassMode::Pair(..)));
+
+        // The C ABI returns it indirectly.
+        let mut fn_abi = new_fn_abi();
+        assert!(fn_abi.adjust_for_foreign_abi(&cx, SpecAbi::C { unwind: false }).is_ok());
+        assert!(matches!(fn_abi.ret.mode, PassMode::Indirect { .. }));
+    });
+}
+
+#[test]
+fn adjust_for_rust_abi_modes() {
+    use crate::spec::abi::Abi as SpecAbi;
+
+    let cx = TestCx::new("x86_64-unknown-linux-gnu");
+    let dl = cx.data_layout();
+    let i32_layout = LayoutS::scalar(dl, int_scalar(Integer::I32, true));
+    let pair_layout =
+        scalar_pair_layout(dl, int_scalar(Integer::I32, true), int_scalar(Integer::I64, true));
+    let u8_layout = LayoutS::scalar(dl, int_scalar(Integer::I8, false));
+    let small_layout = array_layout(&u8_layout, 4);
+    let large_layout = array_layout(&u8_layout, 32);
+
+    // `fn(i32, (i32, i64), [u8; 4], [u8; 32]) -> i32`
+    let mut fn_abi = FnAbi::new_internal(
+        arg_abi(dl, &i32_layout),
+        vec![
+            arg_abi(dl, &i32_layout),
+            arg_abi(dl, &pair_layout),
+            arg_abi(dl, &small_layout),
+            arg_abi(dl, &large_layout),
+        ],
+        Conv::Rust,
+    );
+    fn_abi.adjust_for_rust_abi(&cx, SpecAbi::Rust);
+
+    assert!(fn_abi.ret.is_direct());
+    assert!(fn_abi.args[0].is_direct());
+    assert!(fn_abi.args[1].is_pair());
+    assert!(matches!(
+        &fn_abi.args[2].mode,
+        PassMode::Cast(cast) if *cast == CastTarget::from(Reg::i32())
+    ));
+    assert!(fn_abi.args[3].is_sized_indirect());
+}
+
+#[test]
+fn x86_64_struct_by_value() {
+    let triple = "x86_64-unknown-linux-gnu";
+    let cx = TestCx::new(triple);
+    let dl = cx.data_layout();
+    let i64_layout = LayoutS::scalar(dl, int_scalar(Integer::I64, true));
+    let f64_layout = scalar_layout(dl, Primitive::F64);
+    let i64_field = ty_and_layout(&[], &i64_layout);
+    let f64_field = ty_and_layout(&[], &f64_layout);
+
+    // `struct { a: i64, b: f64 }` is split between an integer and an SSE register.
+    let small_fields = [i64_field, f64_field];
+    let small_layout = struct_layout(&small_fields);
+    let small = ty_and_layout(&small_fields, &small_layout);
+
+    // `struct { a: i64, b: i64, c: i64 }` is too large for registers.
+    let large_fields = [i64_field, i64_field, i64_field];
+    let large_layout = struct_layout(&large_fields);
+    let large = ty_and_layout(&large_fields, &large_layout);
+
+    let fn_abi = foreign_fn_abi_for_test(triple, &[small, large], i64_field);
+    assert!(matches!(
+        &fn_abi.args[0].mode,
+        PassMode::Cast(cast) if *cast == CastTarget::pair(Reg::i64(), Reg::f64())
+    ));
+    assert!(matches!(fn_abi.args[1].mode, PassMode::Indirect { on_stack: true, .. }));
+    assert!(fn_abi.ret.is_direct());
+
+    // Returning the large struct goes through a hidden pointer instead.
+    let fn_abi = foreign_fn_abi_for_test(triple, &[], large);
+    assert!(matches!(fn_abi.ret.mode, PassMode::Indirect { on_stack: false, .. }));
+}
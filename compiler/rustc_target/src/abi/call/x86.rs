@@ -1,4 +1,4 @@
-use crate::abi::call::{ArgAttribute, FnAbi, PassMode, Reg, RegKind};
+use crate::abi::call::{ArgAttribute, FnAbi, PassMode, Reg};
 use crate::abi::{HasDataLayout, TyAbiInterface};
 use crate::spec::HasTargetSpec;
 
@@ -89,7 +89,7 @@ pub fn compute_abi_info<'a, Ty, C>(cx: &C, fn_abi: &mut FnAbi<'a, Ty>, flavor: F
             // At this point we know this must be a primitive of sorts.
             let unit = arg.layout.homogeneous_aggregate(cx).unwrap().unit().unwrap();
             assert_eq!(unit.size, arg.layout.size);
-            if unit.kind == RegKind::Float {
+            if unit.is_float() {
                 continue;
             }
 
@@ -105,7 +105,7 @@ pub fn compute_abi_info<'a, Ty, C>(cx: &C, fn_abi: &mut FnAbi<'a, Ty>, flavor: F
 
             free_regs -= size_in_regs;
 
-            if arg.layout.size.bits() <= 32 && unit.kind == RegKind::Integer {
+            if arg.layout.size.bits() <= 32 && unit.is_integer() {
                 attrs.set(ArgAttribute::InReg);
             }
 
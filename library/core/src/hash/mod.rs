@@ -722,46 +722,12 @@ fn hash<H: Hasher>(&self, _: &mut H) {
         }
     }
 
-    macro_rules! impl_hash_tuple {
-        () => (
-            #[stable(feature = "rust1", since = "1.0.0")]
-            impl Hash for () {
-                #[inline]
-                fn hash<H: Hasher>(&self, _state: &mut H) {}
-            }
-        );
-
-        ( $($name:ident)+) => (
-            #[stable(feature = "rust1", since = "1.0.0")]
-            impl<$($name: Hash),+> Hash for ($($name,)+) where last_type!($($name,)+): ?Sized {
-                #[allow(non_snake_case)]
-                #[inline]
-                fn hash<S: Hasher>(&self, state: &mut S) {
-                    let ($(ref $name,)+) = *self;
-                    $($name.hash(state);)+
-                }
-            }
-        );
-    }
-
-    macro_rules! last_type {
-        ($a:ident,) => { $a };
-        ($a:ident, $($rest_a:ident,)+) => { last_type!($($rest_a,)+) };
-    }
-
-    impl_hash_tuple! {}
-    impl_hash_tuple! { A }
-    impl_hash_tuple! { A B }
-    impl_hash_tuple! { A B C }
-    impl_hash_tuple! { A B C D }
-    impl_hash_tuple! { A B C D E }
-    impl_hash_tuple! { A B C D E F }
-    impl_hash_tuple! { A B C D E F G }
-    impl_hash_tuple! { A B C D E F G H }
-    impl_hash_tuple! { A B C D E F G H I }
-    impl_hash_tuple! { A B C D E F G H I J }
-    impl_hash_tuple! { A B C D E F G H I J K }
-    impl_hash_tuple! { A B C D E F G H I J K L }
+    // Tuples with at least one element implement `Hash` in `tuple.rs`.
+    #[stable(feature = "rust1", since = "1.0.0")]
+    impl Hash for () {
+        #[inline]
+        fn hash<H: Hasher>(&self, _state: &mut H) {}
+    }
 
     #[stable(feature = "rust1", since = "1.0.0")]
     impl<T: Hash> Hash for [T] {
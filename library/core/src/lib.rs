@@ -340,9 +340,11 @@ pub mod assert_matches {
 
 // note: does not need to be public
 mod bool;
-mod tuple;
 mod unit;
 
+#[unstable(feature = "core_tuple_module", issue = "none")]
+pub mod tuple;
+
 #[stable(feature = "core_primitive", since = "1.43.0")]
 pub mod primitive;
 
@@ -1,12 +1,91 @@
+//! Helpers for tuples.
+//!
+//! See the [`tuple`](prim@tuple) primitive type for the documentation of
+//! tuples themselves.
+
 // See src/libstd/primitive_docs.rs for documentation.
 
 use crate::cmp::Ordering::*;
 use crate::cmp::*;
+use crate::hash::{Hash, Hasher};
+
+/// Transforms every element of a tuple whose elements all have the same type.
+///
+/// This is implemented for tuples of arity 12 or less whose elements are all of
+/// type `T`. Tuples of different element types, like `(u8, u16)`, don't
+/// implement it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_tuple_module, tuple_map)]
+/// use std::tuple::TupleMap;
+///
+/// let doubled = (1, 2, 3).map(|x| x * 2);
+/// assert_eq!(doubled, (2, 4, 6));
+///
+/// let lengths = ("a", "bc").map(str::len);
+/// assert_eq!(lengths, (1, 2));
+/// ```
+#[unstable(feature = "tuple_map", issue = "none")]
+pub trait TupleMap<T, U> {
+    /// The tuple of the same arity, with elements of type `U`.
+    type Output;
+
+    /// Calls `f` on each element, in order, and returns a tuple of the results.
+    fn map<F: FnMut(T) -> U>(self, f: F) -> Self::Output;
+}
+
+/// Swaps the two elements of a pair.
+///
+/// The pair is consumed, so this also works for elements that aren't `Copy`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_tuple_module, tuple_swap)]
+/// use std::tuple::TupleSwap;
+///
+/// let swapped: (u8, String) = (String::from("a"), 1).swap();
+/// assert_eq!(swapped, (1, String::from("a")));
+/// ```
+#[unstable(feature = "tuple_swap", issue = "none")]
+pub trait TupleSwap {
+    /// The pair with its element types flipped.
+    type Output;
+
+    /// Returns the pair with its elements in the opposite order.
+    fn swap(self) -> Self::Output;
+}
+
+#[unstable(feature = "tuple_swap", issue = "none")]
+impl<A, B> TupleSwap for (A, B) {
+    type Output = (B, A);
+
+    #[inline]
+    fn swap(self) -> (B, A) {
+        (self.1, self.0)
+    }
+}
 
 // macro for implementing n-ary tuple functions and operations
 macro_rules! tuple_impls {
     ( $( $Tuple:ident( $( $T:ident )+ ) )+ ) => {
         $(
+            // Tuples are `Clone` and `Copy` through built-in impls in the compiler, which
+            // don't show up in the documentation. These impls are only there for rustdoc.
+            #[cfg(doc)]
+            #[stable(feature = "rust1", since = "1.0.0")]
+            impl<$($T:Clone),+> Clone for ($($T,)+) {
+                fn clone(&self) -> Self {
+                    ($( ${ignore(T)} self.${index()}.clone(), )+)
+                }
+            }
+
+            #[cfg(doc)]
+            #[stable(feature = "rust1", since = "1.0.0")]
+            impl<$($T:Copy),+> Copy for ($($T,)+) {}
+
             #[stable(feature = "rust1", since = "1.0.0")]
             impl<$($T:PartialEq),+> PartialEq for ($($T,)+) where last_type!($($T,)+): ?Sized {
                 #[inline]
@@ -57,6 +136,14 @@ fn cmp(&self, other: &($($T,)+)) -> Ordering {
                 }
             }
 
+            #[stable(feature = "rust1", since = "1.0.0")]
+            impl<$($T:Hash),+> Hash for ($($T,)+) where last_type!($($T,)+): ?Sized {
+                #[inline]
+                fn hash<S: Hasher>(&self, state: &mut S) {
+                    $( ${ignore(T)} self.${index()}.hash(state); )+
+                }
+            }
+
             #[stable(feature = "rust1", since = "1.0.0")]
             impl<$($T:Default),+> Default for ($($T,)+) {
                 #[inline]
@@ -64,6 +151,16 @@ fn default() -> ($($T,)+) {
                     ($({ let x: $T = Default::default(); x},)+)
                 }
             }
+
+            #[unstable(feature = "tuple_map", issue = "none")]
+            impl<E, U> TupleMap<E, U> for ($( ${ignore(T)} E, )+) {
+                type Output = ($( ${ignore(T)} U, )+);
+
+                #[inline]
+                fn map<F: FnMut(E) -> U>(self, mut f: F) -> Self::Output {
+                    ($( ${ignore(T)} f(self.${index()}), )+)
+                }
+            }
         )+
     }
 }
@@ -79,6 +79,9 @@
 #![feature(once_cell)]
 #![feature(option_result_contains)]
 #![feature(unsized_tuple_coercion)]
+#![feature(core_tuple_module)]
+#![feature(tuple_map)]
+#![feature(tuple_swap)]
 #![feature(const_option)]
 #![feature(const_option_ext)]
 #![feature(const_result)]
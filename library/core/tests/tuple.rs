@@ -1,4 +1,7 @@
 use std::cmp::Ordering::{Equal, Greater, Less};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::tuple::{TupleMap, TupleSwap};
 
 #[test]
 fn test_clone() {
@@ -7,6 +10,16 @@ fn test_clone() {
     assert_eq!(a, b);
 }
 
+#[test]
+fn test_clone_non_copy() {
+    let a = (String::from("a"), vec![1u8, 2]);
+    let b = a.clone();
+    assert_eq!(a, b);
+    // The clone owns its own copies of the elements.
+    assert_ne!(a.0.as_ptr(), b.0.as_ptr());
+    assert_ne!(a.1.as_ptr(), b.1.as_ptr());
+}
+
 #[test]
 fn test_partial_eq() {
     let (small, big) = ((1, 2, 3), (3, 2, 1));
@@ -41,6 +54,31 @@ fn test_partial_ord() {
     assert!(!((2.0f64, 2.0f64) < (2.0, f64::NAN)));
 }
 
+#[test]
+fn test_partial_ord_agrees_with_partial_cmp() {
+    let nan = f64::NAN;
+    let pairs = [
+        ((nan, 1), (nan, 2)),
+        ((nan, 2), (nan, 1)),
+        ((nan, 1), (1.0, 1)),
+        ((1.0, 1), (nan, 1)),
+        ((1.0, 1), (1.0, 2)),
+        ((1.0, 2), (1.0, 2)),
+    ];
+    for (a, b) in pairs {
+        let ordering = a.partial_cmp(&b);
+        assert_eq!(a < b, ordering == Some(Less), "{:?} < {:?}", a, b);
+        assert_eq!(a <= b, matches!(ordering, Some(Less | Equal)), "{:?} <= {:?}", a, b);
+        assert_eq!(a > b, ordering == Some(Greater), "{:?} > {:?}", a, b);
+        assert_eq!(a >= b, matches!(ordering, Some(Greater | Equal)), "{:?} >= {:?}", a, b);
+    }
+
+    // A NaN before the last element makes the tuples incomparable, whatever follows.
+    assert_eq!((nan, 1).partial_cmp(&(nan, 2)), None);
+    assert!(!((nan, 1) < (nan, 2)));
+    assert!(!((nan, 1, 1.0) >= (0.0, 1, 1.0)));
+}
+
 #[test]
 fn test_ord() {
     let (small, big) = ((1, 2, 3), (3, 2, 1));
@@ -59,3 +97,51 @@ fn test_show() {
     let s = format!("{:?}", (1, "hi", true));
     assert_eq!(s, "(1, \"hi\", true)");
 }
+
+#[test]
+fn test_hash() {
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut hasher = DefaultHasher::new();
+    1u8.hash(&mut hasher);
+    "x".hash(&mut hasher);
+    assert_eq!(hash(&(1u8, "x")), hasher.finish());
+
+    let t12 = (1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64, 'a', "b", true, ());
+    assert_eq!(hash(&t12), hash(&t12.clone()));
+
+    // The last element may be unsized.
+    let unsized_last: &(u8, [u8]) = &(1, [2, 3]);
+    let mut hasher = DefaultHasher::new();
+    1u8.hash(&mut hasher);
+    [2u8, 3].hash(&mut hasher);
+    assert_eq!(hash(unsized_last), hasher.finish());
+}
+
+#[test]
+fn test_map() {
+    assert_eq!((1, 2).map(|x| x + 1), (2, 3));
+    assert_eq!((1u8, 2, 3).map(u32::from), (1u32, 2, 3));
+
+    // The elements are transformed in order.
+    let mut seen = Vec::new();
+    let strings = ('a', 'b', 'c').map(|c| {
+        seen.push(c);
+        c.to_string()
+    });
+    assert_eq!(strings, (String::from("a"), String::from("b"), String::from("c")));
+    assert_eq!(seen, ['a', 'b', 'c']);
+}
+
+#[test]
+fn test_swap() {
+    assert_eq!((1, 2).swap(), (2, 1));
+
+    let swapped: (u8, String) = (String::from("a"), 1u8).swap();
+    assert_eq!(swapped, (1, String::from("a")));
+    assert_eq!(swapped.swap(), (String::from("a"), 1));
+}
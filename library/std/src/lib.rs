@@ -308,6 +308,7 @@
 #![feature(const_format_args)]
 #![feature(core_ffi_c)]
 #![feature(core_panic)]
+#![feature(core_tuple_module)]
 #![feature(custom_test_frameworks)]
 #![feature(edition_panic)]
 #![feature(format_args_nl)]
@@ -459,6 +460,8 @@
 pub use core::ptr;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::result;
+#[unstable(feature = "core_tuple_module", issue = "none")]
+pub use core::tuple;
 #[stable(feature = "i128", since = "1.26.0")]
 #[allow(deprecated, deprecated_in_future)]
 pub use core::u128;
@@ -140,6 +140,9 @@ enum Class {
     PreludeVal,
     QuestionMark,
     Decoration(&'static str),
+    /// The opening delimiter of an array, slice or tuple type, which can link to the
+    /// primitive type's documentation.
+    Delimiter(Span),
 }
 
 impl Class {
@@ -164,6 +167,7 @@ fn as_html(self) -> &'static str {
             Class::PreludeVal => "prelude-val",
             Class::QuestionMark => "question-mark",
             Class::Decoration(kind) => kind,
+            Class::Delimiter(_) => "primitive",
         }
     }
 
@@ -171,7 +175,7 @@ fn as_html(self) -> &'static str {
     /// a "span" (a tuple representing `(lo, hi)` equivalent of `Span`).
     fn get_span(self) -> Option<Span> {
         match self {
-            Self::Ident(sp) | Self::Self_(sp) => Some(sp),
+            Self::Ident(sp) | Self::Self_(sp) | Self::Delimiter(sp) => Some(sp),
             _ => None,
         }
     }
@@ -539,6 +543,11 @@ fn advance(
             | TokenKind::Lt
             | TokenKind::Gt => Class::Op,
 
+            // Might be the start of an array, slice or tuple type.
+            TokenKind::OpenParen | TokenKind::OpenBracket if !self.in_attribute => {
+                Class::Delimiter(self.new_span(before, text))
+            }
+
             // Miscellaneous, no highlighting.
             TokenKind::Dot
             | TokenKind::Semi
@@ -747,6 +756,10 @@ fn string<T: Display>(
             return;
         }
     }
+    if let Class::Delimiter(_) = klass {
+        // Delimiters are only highlighted when they are a link.
+        return write!(out, "{}", text_s);
+    }
     write!(out, "<span class=\"{}\">{}</span>", klass.as_html(), text_s);
 }
 
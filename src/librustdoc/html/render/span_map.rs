@@ -2,13 +2,18 @@
 use crate::html::sources;
 
 use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::{par_iter, ParallelIterator};
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, CRATE_DEF_ID};
 use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{ExprKind, GenericParam, GenericParamKind, HirId, Mod, Node};
+use rustc_hir::{
+    Expr, ExprKind, GenericParam, GenericParamKind, HirId, ItemKind, Mod, Node, PatKind, QPath,
+    TyKind, UseKind,
+};
 use rustc_middle::hir::nested_filter;
-use rustc_middle::ty::TyCtxt;
-use rustc_span::Span;
+use rustc_middle::ty::{self, Instance, InstanceDef, TyCtxt, TypeckResults};
+use rustc_span::symbol::Ident;
+use rustc_span::{BytePos, Span};
 
 use std::path::{Path, PathBuf};
 
@@ -43,19 +48,38 @@
     include_sources: bool,
     generate_link_to_definition: bool,
 ) -> (FxHashMap<PathBuf, String>, FxHashMap<Span, LinkFromSrc>) {
-    let mut visitor = SpanMapVisitor { tcx, matches: FxHashMap::default() };
-
     if include_sources {
-        if generate_link_to_definition {
-            tcx.hir().walk_toplevel_module(&mut visitor);
-        }
+        let matches =
+            if generate_link_to_definition { collect_span_map(tcx) } else { FxHashMap::default() };
         let sources = sources::collect_local_sources(tcx, src_root, &krate);
-        (sources, visitor.matches)
+        (sources, matches)
     } else {
         (Default::default(), Default::default())
     }
 }
 
+/// Builds the `span` correspondance map of the local crate.
+///
+/// Each top-level item is walked on its own, in parallel with the parallel compiler, and the
+/// resulting maps are merged in the order of the items. They can't conflict, as the spans of two
+/// different items are disjoint.
+fn collect_span_map(tcx: TyCtxt<'_>) -> FxHashMap<Span, LinkFromSrc> {
+    // The crate root itself needs no link (see `visit_mod`), only its items do.
+    let (top_mod, _, _) = tcx.hir().get_module(CRATE_DEF_ID);
+    let partials: Vec<_> = par_iter(top_mod.item_ids)
+        .map(|&item_id| {
+            let mut visitor = SpanMapVisitor { tcx, matches: FxHashMap::default() };
+            visitor.visit_nested_item(item_id);
+            visitor.matches
+        })
+        .collect();
+    let mut matches = FxHashMap::default();
+    for partial in partials {
+        matches.extend(partial);
+    }
+    matches
+}
+
 struct SpanMapVisitor<'tcx> {
     crate tcx: TyCtxt<'tcx>,
     crate matches: FxHashMap<Span, LinkFromSrc>,
@@ -65,10 +89,13 @@ impl<'tcx> SpanMapVisitor<'tcx> {
     /// This function is where we handle `hir::Path` elements and add them into the "span map".
     fn handle_path(&mut self, path: &rustc_hir::Path<'_>, path_span: Option<Span>) {
         let info = match path.res {
-            // FIXME: For now, we only handle `DefKind` if it's not `DefKind::TyParam` or
-            // `DefKind::Macro`. Would be nice to support them too alongside the other `DefKind`
-            // (such as primitive types!).
-            Res::Def(kind, def_id) if kind != DefKind::TyParam => {
+            // FIXME: For now, we only handle `DefKind` if it's not `DefKind::Macro`. Would be nice
+            // to support them too alongside the other `DefKind`.
+            //
+            // Type parameters are resolved to the generic parameter introducing them, which may
+            // belong to an enclosing item (like an `impl`), so they link to it like any other
+            // local item.
+            Res::Def(kind, def_id) => {
                 if matches!(kind, DefKind::Macro(_)) {
                     return;
                 }
@@ -76,11 +103,27 @@ fn handle_path(&mut self, path: &rustc_hir::Path<'_>, path_span: Option<Span>) {
             }
             Res::Local(_) => None,
             Res::PrimTy(p) => {
-                // FIXME: Doesn't handle "path-like" primitives like arrays or tuples.
+                // "Path-like" primitives such as arrays or tuples are handled in `visit_ty`.
                 let span = path_span.unwrap_or(path.span);
                 self.matches.insert(span, LinkFromSrc::Primitive(PrimitiveType::from(p)));
                 return;
             }
+            Res::SelfTy { trait_, alias_to } => {
+                // `Self` links to the type of the impl (or the type definition) it is used in,
+                // and to the trait itself in the default methods of a trait.
+                let def_id = match alias_to {
+                    Some((def_id, _)) if self.tcx.def_kind(def_id) == DefKind::Impl => {
+                        self.tcx.type_of(def_id).ty_adt_def().map(|adt| adt.did())
+                    }
+                    Some((def_id, _)) => Some(def_id),
+                    None => trait_,
+                };
+                if let Some(def_id) = def_id {
+                    let span = path_span.unwrap_or(path.span);
+                    self.matches.insert(span, self.link_from_def_id(def_id));
+                }
+                return;
+            }
             Res::Err => return,
             _ => return,
         };
@@ -91,6 +134,78 @@ fn handle_path(&mut self, path: &rustc_hir::Path<'_>, path_span: Option<Span>) {
             self.matches.insert(path_span.unwrap_or(path.span), LinkFromSrc::External(def_id));
         }
     }
+
+    /// Returns the typeck results of the body containing `hir_id`.
+    fn typeck_results(&self, hir_id: HirId) -> &'tcx TypeckResults<'tcx> {
+        let hir = self.tcx.hir();
+        let body_id = hir.enclosing_body_owner(hir_id);
+        // FIXME: this is showing error messages for parts of the code that are not
+        // compiled (because of cfg)!
+        //
+        // See discussion in https://github.com/rust-lang/rust/issues/69426#issuecomment-1019412352
+        self.tcx.typeck_body(hir.maybe_body_owned_by(body_id).expect("a body which isn't a body"))
+    }
+
+    /// If `def_id` is a trait method called by `hir_id`, returns the method of the impl it
+    /// resolves to, so that the link points to the code which is actually called. Otherwise, for
+    /// instance in generic contexts or on trait objects, returns the trait method itself.
+    fn resolve_trait_method(
+        &self,
+        hir_id: HirId,
+        def_id: DefId,
+        typeck_results: &TypeckResults<'tcx>,
+    ) -> DefId {
+        if self.tcx.trait_of_item(def_id).is_none() {
+            return def_id;
+        }
+        let hir = self.tcx.hir();
+        let param_env = self.tcx.param_env(hir.local_def_id(hir.enclosing_body_owner(hir_id)));
+        let substs = self.tcx.erase_regions(typeck_results.node_substs(hir_id));
+        match Instance::resolve(self.tcx, param_env, def_id, substs) {
+            Ok(Some(Instance { def: InstanceDef::Item(item), .. })) => item.did,
+            _ => def_id,
+        }
+    }
+
+    fn link_from_def_id(&self, def_id: DefId) -> LinkFromSrc {
+        match self.tcx.hir().span_if_local(def_id) {
+            Some(span) => LinkFromSrc::Local(clean::Span::new(span)),
+            None => LinkFromSrc::External(def_id),
+        }
+    }
+
+    /// Returns the span of the `|..|` of the closure `expr` evaluates to, if it's a local one.
+    fn closure_decl_span(&self, expr: &Expr<'_>) -> Option<Span> {
+        let ty = self.typeck_results(expr.hir_id).expr_ty(expr);
+        let ty::Closure(def_id, _) = *ty.kind() else { return None };
+        match self.tcx.hir().get_if_local(def_id)? {
+            Node::Expr(Expr { kind: ExprKind::Closure(_, _, _, fn_decl_span, _), .. }) => {
+                Some(*fn_decl_span)
+            }
+            _ => None,
+        }
+    }
+
+    /// Links the fields of a struct expression or of a struct pattern to their definition.
+    fn handle_struct_fields(
+        &mut self,
+        qpath: &QPath<'_>,
+        hir_id: HirId,
+        fields: impl Iterator<Item = (HirId, Ident)>,
+    ) {
+        let typeck_results = self.typeck_results(hir_id);
+        let Some(adt) = typeck_results.node_type(hir_id).ty_adt_def() else { return };
+        let res = typeck_results.qpath_res(qpath, hir_id);
+        if res == Res::Err {
+            return;
+        }
+        let variant = adt.variant_of_res(res);
+        for (field_hir_id, ident) in fields {
+            if let Some(&index) = typeck_results.field_indices().get(field_hir_id) {
+                self.matches.insert(ident.span, self.link_from_def_id(variant.fields[index].did));
+            }
+        }
+    }
 }
 
 impl<'tcx> Visitor<'tcx> for SpanMapVisitor<'tcx> {
@@ -116,6 +231,24 @@ fn visit_path(&mut self, path: &'tcx rustc_hir::Path<'tcx>, _id: HirId) {
         intravisit::walk_path(self, path);
     }
 
+    fn visit_ty(&mut self, ty: &'tcx rustc_hir::Ty<'tcx>) {
+        let prim = match ty.kind {
+            TyKind::Array(..) => Some(PrimitiveType::Array),
+            TyKind::Slice(_) => Some(PrimitiveType::Slice),
+            TyKind::Tup([]) => Some(PrimitiveType::Unit),
+            TyKind::Tup(_) => Some(PrimitiveType::Tuple),
+            _ => None,
+        };
+        if let Some(prim) = prim {
+            // Only single tokens can be turned into links when rendering the source code, so
+            // the opening delimiter (`[` or `(`) stands for the whole type.
+            let span = ty.span.with_hi(ty.span.lo() + BytePos(1));
+            self.matches.insert(span, LinkFromSrc::Primitive(prim));
+        }
+        // The element types may contain paths which need their own links.
+        intravisit::walk_ty(self, ty);
+    }
+
     fn visit_mod(&mut self, m: &'tcx Mod<'tcx>, span: Span, id: HirId) {
         // To make the difference between "mod foo {}" and "mod foo;". In case we "import" another
         // file, we want to link to it. Otherwise no need to create a link.
@@ -129,34 +262,63 @@ fn visit_mod(&mut self, m: &'tcx Mod<'tcx>, span: Span, id: HirId) {
         intravisit::walk_mod(self, m, id);
     }
 
+    fn visit_pat(&mut self, p: &'tcx rustc_hir::Pat<'tcx>) {
+        if let PatKind::Struct(ref qpath, fields, _) = p.kind {
+            self.handle_struct_fields(qpath, p.hir_id, fields.iter().map(|f| (f.hir_id, f.ident)));
+        }
+        intravisit::walk_pat(self, p);
+    }
+
     fn visit_expr(&mut self, expr: &'tcx rustc_hir::Expr<'tcx>) {
-        if let ExprKind::MethodCall(segment, ..) = expr.kind {
-            if let Some(hir_id) = segment.hir_id {
-                let hir = self.tcx.hir();
-                let body_id = hir.enclosing_body_owner(hir_id);
-                // FIXME: this is showing error messages for parts of the code that are not
-                // compiled (because of cfg)!
-                //
-                // See discussion in https://github.com/rust-lang/rust/issues/69426#issuecomment-1019412352
-                let typeck_results = self.tcx.typeck_body(
-                    hir.maybe_body_owned_by(body_id).expect("a body which isn't a body"),
-                );
-                if let Some(def_id) = typeck_results.type_dependent_def_id(expr.hir_id) {
-                    self.matches.insert(
-                        segment.ident.span,
-                        match hir.span_if_local(def_id) {
-                            Some(span) => LinkFromSrc::Local(clean::Span::new(span)),
-                            None => LinkFromSrc::External(def_id),
-                        },
-                    );
+        match expr.kind {
+            ExprKind::MethodCall(segment, ..) => {
+                if let Some(hir_id) = segment.hir_id {
+                    let typeck_results = self.typeck_results(hir_id);
+                    if let Some(def_id) = typeck_results.type_dependent_def_id(expr.hir_id) {
+                        let def_id = self.resolve_trait_method(expr.hir_id, def_id, typeck_results);
+                        self.matches.insert(segment.ident.span, self.link_from_def_id(def_id));
+                    }
+                }
+            }
+            ExprKind::Call(callee, _) => {
+                // A local binding links to the pattern introducing it, but when it holds a closure
+                // which is called, the closure is more useful. The expression is walked first so
+                // that this link isn't overwritten. Immediately invoked closures need no link.
+                intravisit::walk_expr(self, expr);
+                if let ExprKind::Path(QPath::Resolved(None, path)) = callee.kind {
+                    if let Res::Local(_) = path.res {
+                        if let Some(span) = self.closure_decl_span(callee) {
+                            let link = LinkFromSrc::Local(clean::Span::new(span));
+                            self.matches.insert(path.span, link);
+                        }
+                    }
                 }
+                return;
+            }
+            ExprKind::Struct(qpath, fields, _) => {
+                // In the shorthand `Foo { bar }`, the path to the `bar` variable has the same span
+                // as the field, so we walk the expression first to link the field instead.
+                intravisit::walk_expr(self, expr);
+                let fields = fields.iter().map(|f| (f.hir_id, f.ident));
+                self.handle_struct_fields(qpath, expr.hir_id, fields);
+                return;
             }
+            _ => {}
         }
         intravisit::walk_expr(self, expr);
     }
 
     fn visit_use(&mut self, path: &'tcx rustc_hir::Path<'tcx>, id: HirId) {
         self.handle_path(path, None);
+        // In `use foo::Bar as Baz;`, the new name links to the imported item as well. Glob
+        // imports resolve to the module they import from, so only their path gets a link.
+        if let Some(Node::Item(item)) = self.tcx.hir().find(id) {
+            if matches!(item.kind, ItemKind::Use(_, UseKind::Single))
+                && !path.span.contains(item.ident.span)
+            {
+                self.handle_path(path, Some(item.ident.span));
+            }
+        }
         intravisit::walk_use(self, path, id);
     }
 }
@@ -0,0 +1,32 @@
+// ignore-tidy-linelength
+// compile-flags: -Zunstable-options --generate-link-to-definition
+
+// Each top-level item is walked separately, links across them must all be kept.
+
+#![crate_name = "foo"]
+
+// @has 'src/foo/check-source-code-urls-to-def-modules.rs.html'
+
+pub mod a {
+    pub struct A;
+
+    pub mod nested {
+        pub fn make() -> super::A {
+            super::A
+        }
+    }
+}
+
+pub mod b {
+    // @has - '//a[@href="../../src/foo/check-source-code-urls-to-def-modules.rs.html#11"]' 'crate::a::A'
+    // @has - '//a[@href="../../src/foo/check-source-code-urls-to-def-modules.rs.html#14-16"]' 'make'
+    pub fn make() -> crate::a::A {
+        crate::a::nested::make()
+    }
+}
+
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def-modules.rs.html#23-25"]' 'make'
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def-modules.rs.html#11"]' 'a::A'
+pub fn root() -> a::A {
+    b::make()
+}
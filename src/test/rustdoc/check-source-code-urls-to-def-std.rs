@@ -15,3 +15,11 @@ pub fn foo(a: u32, b: &str, c: String) {
     let y: bool = true;
     babar();
 }
+
+// @has - '//a[@href="{{channel}}/std/primitive.array.html"]' '['
+// @has - '//a[@href="{{channel}}/std/primitive.tuple.html"]' '('
+// @has - '//a[@href="{{channel}}/std/primitive.u8.html"]' 'u8'
+// @has - '//a[@href="{{channel}}/std/string/struct.String.html"]' 'String'
+pub fn bar(a: (String, u8)) {
+    let x: [u32; 4] = [0; 4];
+}
@@ -49,3 +49,84 @@ pub fn foo2<T: bar::sub::Trait, V: Trait>(t: &T, v: &V, b: bool) {}
 // @has - '//a[@href="../../foo/primitive.bool.html"]' 'bool'
 #[doc(primitive = "bool")]
 mod whatever {}
+
+pub struct Wrapper<A>(A);
+
+// Type parameters link to the generics introducing them, including the ones of the enclosing
+// `impl`.
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#59"]' 'A'
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#60"]' 'B'
+impl<A: Clone> Wrapper<A> {
+    pub fn pair<B>(&self, b: B) -> (A, B) {
+        let a: A = self.0.clone();
+        let b: B = b;
+        (a, b)
+    }
+}
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+// Fields of struct expressions and patterns link to their definition, including shorthands.
+// @count - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#68"]' 2
+// @count - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#69"]' 2
+pub fn fields(y: u32) -> u32 {
+    let point = Point { x: 1, y };
+    let Point { x, y: _ } = point;
+    x
+}
+
+pub trait Greet {
+    fn greet(&self) {}
+}
+
+impl Greet for Point {
+    fn greet(&self) {}
+}
+
+// Trait method calls link to the method of the impl when it is known, and to the method of the
+// trait otherwise.
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#86"]' 'greet'
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#82"]' 'greet'
+pub fn greet<G: Greet>(point: &Point, g: &G) {
+    point.greet();
+    g.greet();
+}
+
+// Calls to a closure bound to a local link to the closure, not to the binding.
+// @count - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#102"]' 2
+pub fn closures() -> u32 {
+    let add_one =
+        |_: u32| 1;
+    add_one(1) + add_one(2) + (|x: u32| x)(3)
+}
+
+// A renamed import links both the path and the new name to the imported item.
+// @has - '//a[@href="../../src/foo/auxiliary/source-code-bar.rs.html#9-11"]' 'bar::Bar2'
+// @has - '//a[@href="../../src/foo/auxiliary/source-code-bar.rs.html#9-11"]' 'Renamed'
+pub use bar::Bar2 as Renamed;
+
+// A glob import links to the module it imports from.
+// @has - '//a[@href="../../src/foo/auxiliary/source-code-bar.rs.html#13-17"]' 'bar::sub'
+pub use bar::sub::*;
+
+// `Self` links to the implementing type in impls, and to the trait in trait items.
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#118"]' 'Self'
+// @has - '//a[@href="../../src/foo/check-source-code-urls-to-def.rs.html#130-132"]' 'Self'
+pub struct Counter(u32);
+
+impl Counter {
+    pub fn new() -> Self {
+        Counter(0)
+    }
+
+    pub fn pair() -> (Counter, Counter) {
+        (Self::new(), Self::new())
+    }
+}
+
+pub trait Fresh {
+    fn fresh() -> Self;
+}
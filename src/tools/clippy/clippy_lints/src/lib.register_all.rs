@@ -190,8 +190,11 @@
     LintId::of(methods::SINGLE_CHAR_PATTERN),
     LintId::of(methods::SKIP_WHILE_NEXT),
     LintId::of(methods::STRING_EXTEND_CHARS),
+    LintId::of(methods::STR_SPLIT_LAST),
     LintId::of(methods::SUSPICIOUS_MAP),
     LintId::of(methods::SUSPICIOUS_SPLITN),
+    LintId::of(methods::UNAPPLIED_PERMISSIONS),
+    LintId::of(methods::UNDONE_SET_READONLY),
     LintId::of(methods::UNINIT_ASSUMED_INIT),
     LintId::of(methods::UNNECESSARY_FILTER_MAP),
     LintId::of(methods::UNNECESSARY_FIND_MAP),
@@ -253,11 +256,13 @@
     LintId::of(ranges::MANUAL_RANGE_CONTAINS),
     LintId::of(ranges::RANGE_ZIP_WITH_LEN),
     LintId::of(ranges::REVERSED_EMPTY_RANGES),
+    LintId::of(readonly_metadata_ignored::READONLY_METADATA_IGNORED),
     LintId::of(redundant_clone::REDUNDANT_CLONE),
     LintId::of(redundant_closure_call::REDUNDANT_CLOSURE_CALL),
     LintId::of(redundant_field_names::REDUNDANT_FIELD_NAMES),
     LintId::of(redundant_slicing::REDUNDANT_SLICING),
     LintId::of(redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES),
+    LintId::of(redundant_tuple_rebuild::REDUNDANT_TUPLE_REBUILD),
     LintId::of(reference::DEREF_ADDROF),
     LintId::of(regex::INVALID_REGEX),
     LintId::of(repeat_once::REPEAT_ONCE),
@@ -291,6 +296,8 @@
     LintId::of(transmute::UNSOUND_COLLECTION_TRANSMUTE),
     LintId::of(transmute::WRONG_TRANSMUTE),
     LintId::of(transmuting_null::TRANSMUTING_NULL),
+    LintId::of(transposed_tuple_comparison::TRANSPOSED_TUPLE_COMPARISON),
+    LintId::of(tuple_literal_field::TUPLE_LITERAL_FIELD),
     LintId::of(types::BORROWED_BOX),
     LintId::of(types::BOX_COLLECTION),
     LintId::of(types::REDUNDANT_ALLOCATION),
@@ -243,6 +243,7 @@
     manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
     manual_ok_or::MANUAL_OK_OR,
     manual_strip::MANUAL_STRIP,
+    manual_tuple_default::MANUAL_TUPLE_DEFAULT,
     manual_unwrap_or::MANUAL_UNWRAP_OR,
     map_clone::MAP_CLONE,
     map_err_ignore::MAP_ERR_IGNORE,
@@ -301,6 +302,7 @@
     methods::ITER_NEXT_SLICE,
     methods::ITER_NTH,
     methods::ITER_NTH_ZERO,
+    methods::ITER_NTH_ZERO_IN_LOOP,
     methods::ITER_OVEREAGER_CLONED,
     methods::ITER_SKIP_NEXT,
     methods::ITER_WITH_DRAIN,
@@ -312,6 +314,7 @@
     methods::MAP_COLLECT_RESULT_UNIT,
     methods::MAP_FLATTEN,
     methods::MAP_IDENTITY,
+    methods::MAP_THEN_COUNT,
     methods::MAP_UNWRAP_OR,
     methods::NEEDLESS_SPLITN,
     methods::NEW_RET_NO_SELF,
@@ -328,8 +331,11 @@
     methods::SINGLE_CHAR_PATTERN,
     methods::SKIP_WHILE_NEXT,
     methods::STRING_EXTEND_CHARS,
+    methods::STR_SPLIT_LAST,
     methods::SUSPICIOUS_MAP,
     methods::SUSPICIOUS_SPLITN,
+    methods::UNAPPLIED_PERMISSIONS,
+    methods::UNDONE_SET_READONLY,
     methods::UNINIT_ASSUMED_INIT,
     methods::UNNECESSARY_FILTER_MAP,
     methods::UNNECESSARY_FIND_MAP,
@@ -431,6 +437,7 @@
     ranges::RANGE_PLUS_ONE,
     ranges::RANGE_ZIP_WITH_LEN,
     ranges::REVERSED_EMPTY_RANGES,
+    readonly_metadata_ignored::READONLY_METADATA_IGNORED,
     redundant_clone::REDUNDANT_CLONE,
     redundant_closure_call::REDUNDANT_CLOSURE_CALL,
     redundant_else::REDUNDANT_ELSE,
@@ -439,6 +446,7 @@
     redundant_slicing::DEREF_BY_SLICING,
     redundant_slicing::REDUNDANT_SLICING,
     redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES,
+    redundant_tuple_rebuild::REDUNDANT_TUPLE_REBUILD,
     ref_option_ref::REF_OPTION_REF,
     reference::DEREF_ADDROF,
     regex::INVALID_REGEX,
@@ -494,7 +502,10 @@
     transmute::USELESS_TRANSMUTE,
     transmute::WRONG_TRANSMUTE,
     transmuting_null::TRANSMUTING_NULL,
+    transposed_tuple_comparison::TRANSPOSED_TUPLE_COMPARISON,
     try_err::TRY_ERR,
+    tuple_default_large::TUPLE_DEFAULT_LARGE,
+    tuple_literal_field::TUPLE_LITERAL_FIELD,
     types::BORROWED_BOX,
     types::BOX_COLLECTION,
     types::LINKEDLIST,
@@ -538,6 +549,7 @@
     verbose_file_reads::VERBOSE_FILE_READS,
     wildcard_imports::ENUM_GLOB_USE,
     wildcard_imports::WILDCARD_IMPORTS,
+    world_writable_mode::WORLD_WRITABLE_MODE,
     write::PRINTLN_EMPTY_STRING,
     write::PRINT_LITERAL,
     write::PRINT_STDERR,
@@ -50,6 +50,7 @@
     LintId::of(macro_use::MACRO_USE_IMPORTS),
     LintId::of(manual_assert::MANUAL_ASSERT),
     LintId::of(manual_ok_or::MANUAL_OK_OR),
+    LintId::of(manual_tuple_default::MANUAL_TUPLE_DEFAULT),
     LintId::of(match_on_vec_items::MATCH_ON_VEC_ITEMS),
     LintId::of(matches::MATCH_BOOL),
     LintId::of(matches::MATCH_SAME_ARMS),
@@ -62,6 +63,8 @@
     LintId::of(methods::FROM_ITER_INSTEAD_OF_COLLECT),
     LintId::of(methods::IMPLICIT_CLONE),
     LintId::of(methods::INEFFICIENT_TO_STRING),
+    LintId::of(methods::ITER_NTH_ZERO_IN_LOOP),
+    LintId::of(methods::MAP_THEN_COUNT),
     LintId::of(methods::MAP_UNWRAP_OR),
     LintId::of(methods::UNNECESSARY_JOIN),
     LintId::of(misc::FLOAT_CMP),
@@ -86,6 +89,7 @@
     LintId::of(trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS),
     LintId::of(trait_bounds::TYPE_REPETITION_IN_BOUNDS),
     LintId::of(transmute::TRANSMUTE_PTR_TO_PTR),
+    LintId::of(tuple_default_large::TUPLE_DEFAULT_LARGE),
     LintId::of(types::LINKEDLIST),
     LintId::of(types::OPTION_OPTION),
     LintId::of(unicode::UNICODE_NOT_NFC),
@@ -96,5 +100,6 @@
     LintId::of(unused_self::UNUSED_SELF),
     LintId::of(wildcard_imports::ENUM_GLOB_USE),
     LintId::of(wildcard_imports::WILDCARD_IMPORTS),
+    LintId::of(world_writable_mode::WORLD_WRITABLE_MODE),
     LintId::of(zero_sized_map_values::ZERO_SIZED_MAP_VALUES),
 ])
@@ -19,8 +19,12 @@
     LintId::of(loops::FOR_LOOPS_OVER_FALLIBLES),
     LintId::of(loops::MUT_RANGE_BOUND),
     LintId::of(methods::SUSPICIOUS_MAP),
+    LintId::of(methods::UNAPPLIED_PERMISSIONS),
+    LintId::of(methods::UNDONE_SET_READONLY),
     LintId::of(mut_key::MUTABLE_KEY_TYPE),
     LintId::of(octal_escapes::OCTAL_ESCAPES),
+    LintId::of(readonly_metadata_ignored::READONLY_METADATA_IGNORED),
     LintId::of(suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL),
     LintId::of(suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL),
+    LintId::of(transposed_tuple_comparison::TRANSPOSED_TUPLE_COMPARISON),
 ])
@@ -272,6 +272,7 @@ macro_rules! declare_clippy_lint {
 mod manual_non_exhaustive;
 mod manual_ok_or;
 mod manual_strip;
+mod manual_tuple_default;
 mod manual_unwrap_or;
 mod map_clone;
 mod map_err_ignore;
@@ -336,6 +337,7 @@ macro_rules! declare_clippy_lint {
 mod ptr_offset_with_cast;
 mod question_mark;
 mod ranges;
+mod readonly_metadata_ignored;
 mod redundant_clone;
 mod redundant_closure_call;
 mod redundant_else;
@@ -343,6 +345,7 @@ macro_rules! declare_clippy_lint {
 mod redundant_pub_crate;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
+mod redundant_tuple_rebuild;
 mod ref_option_ref;
 mod reference;
 mod regex;
@@ -372,7 +375,10 @@ macro_rules! declare_clippy_lint {
 mod trait_bounds;
 mod transmute;
 mod transmuting_null;
+mod transposed_tuple_comparison;
 mod try_err;
+mod tuple_default_large;
+mod tuple_literal_field;
 mod types;
 mod undocumented_unsafe_blocks;
 mod undropped_manually_drops;
@@ -401,6 +407,7 @@ macro_rules! declare_clippy_lint {
 mod vec_resize_to_zero;
 mod verbose_file_reads;
 mod wildcard_imports;
+mod world_writable_mode;
 mod write;
 mod zero_div_zero;
 mod zero_sized_map_values;
@@ -867,6 +874,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             ignore_publish: cargo_ignore_publish,
         })
     });
+    let large_tuple_default_threshold = conf.large_tuple_default_threshold;
+    store.register_late_pass(move || {
+        Box::new(tuple_default_large::TupleDefaultLarge::new(
+            large_tuple_default_threshold,
+        ))
+    });
+    store.register_late_pass(|| Box::new(transposed_tuple_comparison::TransposedTupleComparison));
+    store.register_late_pass(|| Box::new(readonly_metadata_ignored::ReadonlyMetadataIgnored));
+    store.register_late_pass(|| Box::new(tuple_literal_field::TupleLiteralField));
+    store.register_late_pass(|| Box::new(manual_tuple_default::ManualTupleDefault));
+    store.register_late_pass(|| Box::new(world_writable_mode::WorldWritableMode));
+    store.register_late_pass(|| Box::new(redundant_tuple_rebuild::RedundantTupleRebuild));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
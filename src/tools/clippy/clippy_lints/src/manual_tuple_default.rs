@@ -0,0 +1,71 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::{get_parent_expr, get_parent_node, in_constant, is_default_equivalent};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Local, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tuple literals whose elements are all the default value of
+    /// their type, like `(0, String::new(), None)`.
+    ///
+    /// ### Why is this bad?
+    /// Tuples implement `Default`, so `Default::default()` says the same thing
+    /// without spelling out every element's default value.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let state: (u32, String, Option<u8>) = (0, String::new(), None);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let state: (u32, String, Option<u8>) = Default::default();
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub MANUAL_TUPLE_DEFAULT,
+    pedantic,
+    "building a tuple of default values by hand"
+}
+
+declare_lint_pass!(ManualTupleDefault => [MANUAL_TUPLE_DEFAULT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualTupleDefault {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Tup(elems) = expr.kind;
+            // `Default` is only implemented for tuples of arity 12 or less.
+            if (1..=12).contains(&elems.len());
+            if elems.iter().all(|elem| is_default_equivalent(cx, elem));
+            // `Default::default()` can't be called in constants.
+            if !in_constant(cx, expr.hir_id);
+            // Nested tuples are covered by the outermost one.
+            if !matches!(get_parent_expr(cx, expr), Some(Expr { kind: ExprKind::Tup(_), .. }));
+            then {
+                // The type of an annotated `let` is already known, otherwise it has to be spelled out.
+                // The spelled out type may not be nameable as printed, e.g. because of private
+                // paths or inferred integer types, so that suggestion may be incorrect.
+                let (sugg, applicability) = match get_parent_node(cx.tcx, expr.hir_id) {
+                    Some(Node::Local(Local { ty: Some(_), .. })) => {
+                        ("Default::default()".to_string(), Applicability::MachineApplicable)
+                    },
+                    _ => (
+                        format!("<{}>::default()", cx.typeck_results().expr_ty(expr)),
+                        Applicability::MaybeIncorrect,
+                    ),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_TUPLE_DEFAULT,
+                    expr.span,
+                    "this tuple only contains default values",
+                    "try",
+                    sugg,
+                    applicability,
+                );
+            }
+        }
+    }
+}
@@ -1,9 +1,14 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
-use clippy_utils::source::snippet;
-use clippy_utils::{is_trait_method, meets_msrv, msrvs};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_note, span_lint_and_sugg};
+use clippy_utils::source::{indent_of, reindent_multiline, snippet};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::{is_trait_method, meets_msrv, msrvs, path_to_local_id};
+use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::{BindingAnnotation, ExprKind, PatKind};
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_semver::RustcVersion;
 use rustc_span::sym;
 
@@ -21,8 +26,34 @@ pub(super) fn check<'tcx>(
             return;
         }
 
+        if let Some((find_snippet, applicability)) = identity_predicate(cx, arg) {
+            let iter_snippet = snippet(cx, recv.span, "..");
+            span_lint_and_sugg(
+                cx,
+                FILTER_MAP_NEXT,
+                expr.span,
+                "called `filter_map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
+                 `.find(..)` instead",
+                "try this",
+                format!("{}.find({})", iter_snippet, find_snippet),
+                applicability,
+            );
+            return;
+        }
+
         let msg = "called `filter_map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
                    `.find_map(..)` instead";
+        if finds_option(cx, expr) {
+            span_lint_and_note(
+                cx,
+                FILTER_MAP_NEXT,
+                expr.span,
+                msg,
+                None,
+                "the closure returns a nested `Option`, make sure that `find_map` handles it as intended",
+            );
+            return;
+        }
         let filter_snippet = snippet(cx, arg.span, "..");
         if filter_snippet.lines().count() <= 1 {
             let iter_snippet = snippet(cx, recv.span, "..");
@@ -36,7 +67,121 @@ pub(super) fn check<'tcx>(
                 Applicability::MachineApplicable,
             );
         } else {
-            span_lint(cx, FILTER_MAP_NEXT, expr.span, msg);
+            // Move the closure over verbatim, but it may not be correctly formatted anymore
+            let iter_snippet = snippet(cx, recv.span, "..");
+            let filter_snippet = reindent_multiline(filter_snippet, true, indent_of(cx, arg.span));
+            span_lint_and_sugg(
+                cx,
+                FILTER_MAP_NEXT,
+                expr.span,
+                msg,
+                "try this",
+                format!("{}.find_map({})", iter_snippet, filter_snippet),
+                Applicability::MaybeIncorrect,
+            );
+        }
+    }
+}
+
+/// Turns a `|x| p(x).then_some(x)` closure, which keeps the item as it is, into
+/// the `|&x| p(x)` predicate for `find`.
+///
+/// `find` passes the item by reference, so it can only be bound by a `&x` pattern
+/// if it is `Copy`. Otherwise `x` is a reference in the predicate, which may not
+/// be accepted wherever `x` is used.
+fn identity_predicate(cx: &LateContext<'_>, arg: &hir::Expr<'_>) -> Option<(String, Applicability)> {
+    if_chain! {
+        if let ExprKind::Closure(_, _, body_id, _, _) = arg.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) = param.pat.kind;
+        if let ExprKind::MethodCall(path, [cond, value], _) = body.value.kind;
+        if path.ident.as_str() == "then_some";
+        if cx.typeck_results().expr_ty(cond).is_bool();
+        if path_to_local_id(value, param.pat.hir_id);
+        then {
+            let (pat, mut applicability) = if is_copy(cx, cx.typeck_results().pat_ty(param.pat)) {
+                (format!("&{}", ident), Applicability::MachineApplicable)
+            } else {
+                (ident.to_string(), Applicability::MaybeIncorrect)
+            };
+            let cond = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
+            Some((format!("|{}| {}", pat, cond), applicability))
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether the item found by `expr` is itself an `Option`.
+fn finds_option<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) -> bool {
+    match cx.typeck_results().expr_ty(expr).kind() {
+        ty::Adt(_, substs) => is_type_diagnostic_item(cx, substs.type_at(0), sym::Option),
+        _ => false,
+    }
+}
+
+/// lint use of `filter(..).map(..).next()` for `Iterator`s
+pub(super) fn check_filter_map<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    filter_arg: &'tcx hir::Expr<'_>,
+    map_arg: &'tcx hir::Expr<'_>,
+    msrv: Option<&RustcVersion>,
+) {
+    if is_trait_method(cx, expr, sym::Iterator) {
+        if !meets_msrv(msrv, &msrvs::ITERATOR_FIND_MAP) {
+            return;
+        }
+
+        let msg = "called `filter(..).map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
+                   `.find_map(..)` instead";
+        let merged = if meets_msrv(msrv, &msrvs::BOOL_THEN) {
+            merge_closures(cx, filter_arg, map_arg)
+        } else {
+            None
+        };
+        match merged {
+            Some(find_map_snippet) if find_map_snippet.lines().count() <= 1 => {
+                let iter_snippet = snippet(cx, recv.span, "..");
+                span_lint_and_sugg(
+                    cx,
+                    FILTER_MAP_NEXT,
+                    expr.span,
+                    msg,
+                    "try this",
+                    format!("{}.find_map({})", iter_snippet, find_map_snippet),
+                    Applicability::MachineApplicable,
+                );
+            },
+            _ => span_lint(cx, FILTER_MAP_NEXT, expr.span, msg),
+        }
+    }
+}
+
+/// Merges `|&x| p(x)` and `|x| f(x)` into `|x| p(x).then(|| f(x))`.
+///
+/// The `filter` closure must take its argument by a `&x` pattern, so that `x`
+/// has the same type in both closures.
+fn merge_closures(cx: &LateContext<'_>, filter_arg: &hir::Expr<'_>, map_arg: &hir::Expr<'_>) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Closure(_, _, filter_body_id, _, _) = filter_arg.kind;
+        if let ExprKind::Closure(_, _, map_body_id, _, _) = map_arg.kind;
+        let filter_body = cx.tcx.hir().body(filter_body_id);
+        let map_body = cx.tcx.hir().body(map_body_id);
+        if let [filter_param] = filter_body.params;
+        if let [map_param] = map_body.params;
+        if let PatKind::Ref(filter_pat, _) = filter_param.pat.kind;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, filter_ident, None) = filter_pat.kind;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, map_ident, None) = map_param.pat.kind;
+        if filter_ident.name == map_ident.name;
+        then {
+            let cond = Sugg::hir(cx, &filter_body.value, "..").maybe_par();
+            let value = snippet(cx, map_body.value.span, "..");
+            Some(format!("|{}| {}.then(|| {})", map_ident, cond, value))
+        } else {
+            None
         }
     }
 }
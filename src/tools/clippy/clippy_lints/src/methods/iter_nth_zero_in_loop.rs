@@ -0,0 +1,42 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{get_enclosing_loop_or_closure, is_trait_method};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::ITER_NTH_ZERO_IN_LOOP;
+
+/// Returns `true` if the lint was emitted.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    recv: &hir::Expr<'_>,
+    arg: &hir::Expr<'_>,
+) -> bool {
+    if_chain! {
+        if is_trait_method(cx, expr, sym::Iterator);
+        if let hir::ExprKind::Lit(lit) = &arg.kind;
+        if let LitKind::Int(0, _) = lit.node;
+        if let Some(enclosing) = get_enclosing_loop_or_closure(cx.tcx, expr);
+        if let hir::ExprKind::Loop(..) = enclosing.kind;
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                ITER_NTH_ZERO_IN_LOOP,
+                expr.span,
+                "called `.nth(0)` on a `std::iter::Iterator` inside of a loop",
+                "to advance the iterator by one element, call `.next()` instead",
+                format!("{}.next()", snippet_with_applicability(cx, recv.span, "..", &mut applicability)),
+                applicability,
+            );
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::expr_visitor_no_bodies;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{ExprKind, MatchSource};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MAP_THEN_COUNT;
+
+/// lint use of `map(..).count()` for `Iterator`s, where the closure of the `map` is pure
+///
+/// Returns `true` if the lint was emitted. Otherwise, the `map` may have side effects, which is
+/// left to `suspicious_map`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    recv: &'tcx hir::Expr<'_>,
+    arg: &'tcx hir::Expr<'_>,
+) -> bool {
+    if !is_trait_method(cx, expr, sym::Iterator) {
+        return false;
+    }
+
+    if let ExprKind::Closure(_, _, body_id, _, _) = arg.kind {
+        let body = cx.tcx.hir().body(body_id);
+        if is_pure(cx, &body.value) {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                MAP_THEN_COUNT,
+                expr.span,
+                "called `map(..).count()` on an `Iterator`. The result of `map` is discarded by `count`",
+                "try removing the call to `map`",
+                format!(
+                    "{}.count()",
+                    snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+            return true;
+        }
+    }
+    false
+}
+
+/// Conservatively checks that the closure body has no side effects: it must not contain any
+/// calls (including overloaded operators), assignments, `?` or inline assembly.
+fn is_pure<'tcx>(cx: &LateContext<'tcx>, body: &'tcx hir::Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    let mut pure = true;
+    expr_visitor_no_bodies(|e| {
+        if pure {
+            pure = !(typeck.is_method_call(e)
+                || matches!(
+                    e.kind,
+                    ExprKind::Call(..)
+                        | ExprKind::MethodCall(..)
+                        | ExprKind::Assign(..)
+                        | ExprKind::AssignOp(..)
+                        | ExprKind::Match(_, _, MatchSource::TryDesugar)
+                        | ExprKind::InlineAsm(..)
+                ));
+        }
+        pure
+    })
+    .visit_expr(body);
+    pure
+}
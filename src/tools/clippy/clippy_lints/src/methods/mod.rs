@@ -30,6 +30,7 @@
 mod iter_next_slice;
 mod iter_nth;
 mod iter_nth_zero;
+mod iter_nth_zero_in_loop;
 mod iter_overeager_cloned;
 mod iter_skip_next;
 mod iter_with_drain;
@@ -39,6 +40,7 @@
 mod map_collect_result_unit;
 mod map_flatten;
 mod map_identity;
+mod map_then_count;
 mod map_unwrap_or;
 mod ok_expect;
 mod option_as_ref_deref;
@@ -52,10 +54,13 @@
 mod single_char_pattern;
 mod single_char_push_string;
 mod skip_while_next;
+mod str_split_last;
 mod str_splitn;
 mod string_extend_chars;
 mod suspicious_map;
 mod suspicious_splitn;
+mod unapplied_permissions;
+mod undone_set_readonly;
 mod uninit_assumed_init;
 mod unnecessary_filter_map;
 mod unnecessary_fold;
@@ -74,7 +79,9 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::ty::{contains_adt_constructor, contains_ty, implements_trait, is_copy, is_type_diagnostic_item};
-use clippy_utils::{contains_return, get_trait_def_id, iter_input_pats, meets_msrv, msrvs, paths, return_ty};
+use clippy_utils::{
+    contains_return, get_trait_def_id, is_lint_allowed, iter_input_pats, meets_msrv, msrvs, paths, return_ty,
+};
 use if_chain::if_chain;
 use rustc_hir as hir;
 use rustc_hir::def::Res;
@@ -646,20 +653,24 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `_.filter_map(_).next()`.
+    /// Checks for usage of `_.filter_map(_).next()` and
+    /// `_.filter(_).map(_).next()`.
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely as
-    /// `_.find_map(_)`.
+    /// `_.find_map(_)`, or as `_.find(_)` if the item is only
+    /// kept or dropped with `bool::then_some`.
     ///
     /// ### Example
     /// ```rust
     ///  (0..3).filter_map(|x| if x == 2 { Some(x) } else { None }).next();
+    ///  (0..3).filter(|&x| x == 2).map(|x| x * 2).next();
     /// ```
     /// Can be written as
     ///
     /// ```rust
     ///  (0..3).find_map(|x| if x == 2 { Some(x) } else { None });
+    ///  (0..3).find_map(|x| (x == 2).then(|| x * 2));
     /// ```
     #[clippy::version = "1.36.0"]
     pub FILTER_MAP_NEXT,
@@ -1105,6 +1116,36 @@
     "replace `iter.nth(0)` with `iter.next()`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the use of `iter.nth(0)` inside of a loop.
+    ///
+    /// ### Why is this bad?
+    /// Inside of a loop, `iter.nth(0)` is usually meant to advance the iterator
+    /// by one element, which `iter.next()` states more clearly. This is a more
+    /// specific version of `iter_nth_zero`, which it replaces inside of loops
+    /// when enabled.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut iter = [1, 2, 3].iter();
+    /// // Bad
+    /// while let Some(x) = iter.nth(0) {
+    ///     // ..
+    /// }
+    ///
+    /// // Good
+    /// # let mut iter = [1, 2, 3].iter();
+    /// while let Some(x) = iter.next() {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub ITER_NTH_ZERO_IN_LOOP,
+    pedantic,
+    "replace `iter.nth(0)` with `iter.next()` inside of loops"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for use of `.iter().nth()` (and the related
@@ -1475,6 +1516,29 @@
     "suspicious usage of map"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `map` followed by a `count` on an `Iterator`.
+    ///
+    /// ### Why is this bad?
+    /// `count` discards every item, so the `map` can be removed when its
+    /// closure has no side effects. This lint is a more specific version of
+    /// `suspicious_map` which also suggests the fix.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let _ = (0..3).map(|x| x + 2).count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let _ = (0..3).count();
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub MAP_THEN_COUNT,
+    pedantic,
+    "using `.map(..).count()` where the `map` has no effect on the result"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for `MaybeUninit::uninit().assume_init()`.
@@ -2079,6 +2143,95 @@
     "using `.collect::<Vec<String>>().join(\"\")` on an iterator"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Permissions` obtained from `metadata.permissions()` that are
+    /// modified with `set_readonly` or `set_mode` but never passed on to
+    /// `set_permissions`.
+    ///
+    /// ### Why is this bad?
+    /// `Permissions` is a plain copy of the file's permission bits. Changing it
+    /// has no effect on the file itself until it is applied, so the modification
+    /// is silently a no-op.
+    ///
+    /// ### Known problems
+    /// Any other use of the binding, such as passing it to a function or returning
+    /// it, is assumed to eventually apply the permissions.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut permissions = std::fs::metadata("foo.txt")?.permissions();
+    /// permissions.set_readonly(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut permissions = std::fs::metadata("foo.txt")?.permissions();
+    /// permissions.set_readonly(true);
+    /// std::fs::set_permissions("foo.txt", permissions)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub UNAPPLIED_PERMISSIONS,
+    suspicious,
+    "modifying `Permissions` without applying them with `set_permissions`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `last()` on the iterators returned by `str::split`,
+    /// `str::rsplit`, `str::split_terminator` and `str::rsplit_terminator`,
+    /// when the pattern allows searching from the back.
+    ///
+    /// ### Why is this bad?
+    /// `last()` searches the whole string from the front, while `next_back()`
+    /// only has to search from the back until the last match.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let path = "a/b/c";
+    /// let file = path.split('/').last();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let path = "a/b/c";
+    /// let file = path.split('/').next_back();
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub STR_SPLIT_LAST,
+    perf,
+    "using `.split(..).last()` when `.split(..).next_back()` can search from the back"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Permissions::set_readonly(false)` called directly after
+    /// `set_readonly(true)` on the same binding.
+    ///
+    /// ### Why is this bad?
+    /// The second call undoes the first one before the permissions are used,
+    /// so the first call has no effect. This is most likely a mistake.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut permissions = std::fs::metadata("foo.txt")?.permissions();
+    /// permissions.set_readonly(true);
+    /// permissions.set_readonly(false);
+    /// std::fs::set_permissions("foo.txt", permissions)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub UNDONE_SET_READONLY,
+    suspicious,
+    "calling `set_readonly(false)` right after `set_readonly(true)` on the same `Permissions`"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Option<RustcVersion>,
@@ -2136,6 +2289,7 @@ pub fn new(avoid_breaking_exported_api: bool, msrv: Option<RustcVersion>) -> Sel
     ITER_COUNT,
     ITER_NTH,
     ITER_NTH_ZERO,
+    ITER_NTH_ZERO_IN_LOOP,
     BYTES_NTH,
     ITER_SKIP_NEXT,
     GET_UNWRAP,
@@ -2148,6 +2302,7 @@ pub fn new(avoid_breaking_exported_api: bool, msrv: Option<RustcVersion>) -> Sel
     UNNECESSARY_FIND_MAP,
     INTO_ITER_ON_REF,
     SUSPICIOUS_MAP,
+    MAP_THEN_COUNT,
     UNINIT_ASSUMED_INIT,
     MANUAL_SATURATING_ARITHMETIC,
     ZST_OFFSET,
@@ -2165,6 +2320,9 @@ pub fn new(avoid_breaking_exported_api: bool, msrv: Option<RustcVersion>) -> Sel
     NEEDLESS_SPLITN,
     UNNECESSARY_TO_OWNED,
     UNNECESSARY_JOIN,
+    UNAPPLIED_PERMISSIONS,
+    STR_SPLIT_LAST,
+    UNDONE_SET_READONLY,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -2420,7 +2578,13 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                 Some((name2 @ ("into_iter" | "iter" | "iter_mut"), [recv2], _)) => {
                     iter_count::check(cx, expr, recv2, name2);
                 },
-                Some(("map", [_, arg], _)) => suspicious_map::check(cx, expr, recv, arg),
+                Some(("map", [recv2, arg], _)) => {
+                    if is_lint_allowed(cx, MAP_THEN_COUNT, expr.hir_id)
+                        || !map_then_count::check(cx, expr, recv2, arg)
+                    {
+                        suspicious_map::check(cx, expr, recv, arg);
+                    }
+                },
                 _ => {},
             },
             ("drain", [arg]) => {
@@ -2471,6 +2635,9 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                         iter_overeager_cloned::check(cx, expr, recv2, name, args);
                     }
                 }
+                if name == "last" {
+                    str_split_last::check(cx, expr, recv);
+                }
             },
             ("map", [m_arg]) => {
                 if let Some((name, [recv2, args @ ..], span2)) = method_call(recv) {
@@ -2493,6 +2660,11 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                         ("cloned", []) => iter_overeager_cloned::check(cx, expr, recv2, name, args),
                         ("filter", [arg]) => filter_next::check(cx, expr, recv2, arg),
                         ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, msrv),
+                        ("map", [map_arg]) => {
+                            if let Some(("filter", [recv3, filter_arg], _)) = method_call(recv2) {
+                                filter_map_next::check_filter_map(cx, expr, recv3, filter_arg, map_arg, msrv);
+                            }
+                        },
                         ("iter", []) => iter_next_slice::check(cx, expr, recv2),
                         ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
                         ("skip_while", [_]) => skip_while_next::check(cx, expr),
@@ -2505,7 +2677,13 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                 Some(("cloned", [recv2], _)) => iter_overeager_cloned::check(cx, expr, recv2, name, args),
                 Some(("iter", [recv2], _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, false),
                 Some(("iter_mut", [recv2], _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, true),
-                _ => iter_nth_zero::check(cx, expr, recv, n_arg),
+                _ => {
+                    if is_lint_allowed(cx, ITER_NTH_ZERO_IN_LOOP, expr.hir_id)
+                        || !iter_nth_zero_in_loop::check(cx, expr, recv, n_arg)
+                    {
+                        iter_nth_zero::check(cx, expr, recv, n_arg);
+                    }
+                },
             },
             ("ok_or_else", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "ok_or"),
             ("or_else", [arg]) => {
@@ -2513,6 +2691,11 @@ fn check_methods<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, msrv: Optio
                     unnecessary_lazy_eval::check(cx, expr, recv, arg, "or");
                 }
             },
+            ("set_mode", [_arg]) => unapplied_permissions::check(cx, expr, recv),
+            ("set_readonly", [arg]) => {
+                unapplied_permissions::check(cx, expr, recv);
+                undone_set_readonly::check(cx, expr, recv, arg);
+            },
             ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                 if let Some((Constant::Int(count), _)) = constant(cx, cx.typeck_results(), count_arg) {
                     suspicious_splitn::check(cx, name, expr, recv, count);
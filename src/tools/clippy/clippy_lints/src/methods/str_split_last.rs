@@ -0,0 +1,47 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{implements_trait, match_type};
+use clippy_utils::{is_trait_method, paths};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::STR_SPLIT_LAST;
+
+const SPLIT_ITERATORS: [&[&str]; 4] = [
+    &paths::STR_SPLIT,
+    &paths::STR_RSPLIT,
+    &paths::STR_SPLIT_TERMINATOR,
+    &paths::STR_RSPLIT_TERMINATOR,
+];
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    if_chain! {
+        if is_trait_method(cx, expr, sym::Iterator);
+        // The iterator must be a temporary, so that it doesn't matter that `next_back` doesn't
+        // consume it
+        if let hir::ExprKind::MethodCall(..) = recv.kind;
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        if SPLIT_ITERATORS.iter().any(|path| match_type(cx, recv_ty, path));
+        // Only patterns with a `DoubleEndedSearcher`, like `char`, can be searched from the back
+        if let Some(double_ended_id) = cx.tcx.get_diagnostic_item(sym::DoubleEndedIterator);
+        if implements_trait(cx, recv_ty, double_ended_id, &[]);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                STR_SPLIT_LAST,
+                expr.span,
+                "called `last()` on a `str` split iterator which can be searched from the back",
+                "try",
+                format!(
+                    "{}.next_back()",
+                    snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+                ),
+                applicability,
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::ty::match_type;
+use clippy_utils::visitors::expr_visitor;
+use clippy_utils::{get_parent_expr, path_to_local, path_to_local_id, paths};
+use if_chain::if_chain;
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{Expr, ExprKind, HirId, Node};
+use rustc_lint::LateContext;
+
+use super::UNAPPLIED_PERMISSIONS;
+
+/// Methods on `Permissions` which only touch the local copy.
+const LOCAL_METHODS: [&str; 4] = ["mode", "readonly", "set_mode", "set_readonly"];
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
+    if_chain! {
+        if let Some(local_id) = path_to_local(recv);
+        if match_type(cx, cx.typeck_results().expr_ty(recv), &paths::PERMISSIONS);
+        if let Some(Node::Local(local)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(local_id));
+        if let Some(init) = local.init;
+        if let ExprKind::MethodCall(path, [_], _) = init.kind;
+        if path.ident.name == sym!(permissions);
+        if let Some(body_id) = cx.enclosing_body;
+        if !is_applied(cx, &cx.tcx.hir().body(body_id).value, local_id);
+        then {
+            span_lint_and_note(
+                cx,
+                UNAPPLIED_PERMISSIONS,
+                expr.span,
+                "this change to `Permissions` is never applied",
+                Some(local.span),
+                "the permissions were copied here; pass them to `set_permissions` to apply the change",
+            );
+        }
+    }
+}
+
+/// Checks whether the `Permissions` binding is used for anything other than calling one of the
+/// `LOCAL_METHODS` on it, e.g. passing it to `std::fs::set_permissions` or returning it.
+fn is_applied<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'_>, local_id: HirId) -> bool {
+    let mut applied = false;
+    expr_visitor(cx, |e| {
+        if !applied && path_to_local_id(e, local_id) {
+            applied = !matches!(
+                get_parent_expr(cx, e),
+                Some(Expr { kind: ExprKind::MethodCall(path, [recv, ..], _), .. })
+                    if recv.hir_id == e.hir_id && LOCAL_METHODS.contains(&path.ident.name.as_str())
+            );
+        }
+        !applied
+    })
+    .visit_expr(body);
+    applied
+}
@@ -0,0 +1,45 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::ty::match_type;
+use clippy_utils::{get_parent_node, path_to_local, path_to_local_id, paths};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_hir::{Expr, ExprKind, HirId, Node, StmtKind};
+use rustc_lint::LateContext;
+
+use super::UNDONE_SET_READONLY;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    if_chain! {
+        if is_bool_lit(arg, false);
+        if let Some(local_id) = path_to_local(recv);
+        if match_type(cx, cx.typeck_results().expr_ty(recv).peel_refs(), &paths::PERMISSIONS);
+        if let Some(Node::Stmt(stmt)) = get_parent_node(cx.tcx, expr.hir_id);
+        if let Some(Node::Block(block)) = get_parent_node(cx.tcx, stmt.hir_id);
+        if let Some(idx) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id);
+        if idx > 0;
+        if let StmtKind::Semi(prev) = block.stmts[idx - 1].kind;
+        if is_set_readonly_true(prev, local_id);
+        then {
+            span_lint_and_note(
+                cx,
+                UNDONE_SET_READONLY,
+                expr.span,
+                "this call to `set_readonly(false)` immediately undoes the previous `set_readonly(true)`",
+                Some(prev.span),
+                "the permissions were made read-only here",
+            );
+        }
+    }
+}
+
+fn is_set_readonly_true(expr: &Expr<'_>, local_id: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::MethodCall(path, [recv, arg], _)
+            if path.ident.name == sym!(set_readonly) && path_to_local_id(recv, local_id) && is_bool_lit(arg, true)
+    )
+}
+
+fn is_bool_lit(expr: &Expr<'_>, value: bool) -> bool {
+    matches!(expr.kind, ExprKind::Lit(ref lit) if lit.node == LitKind::Bool(value))
+}
@@ -0,0 +1,95 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::match_type;
+use clippy_utils::visitors::expr_visitor;
+use clippy_utils::{get_parent_expr, get_parent_node, path_res, path_to_local_id, paths};
+use if_chain::if_chain;
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{Expr, ExprKind, HirId, Local, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Permissions` obtained with `metadata().permissions()` and bound
+    /// to a variable, which are then never used: no method is called on them and
+    /// they aren't passed anywhere.
+    ///
+    /// ### Why is this bad?
+    /// Fetching the permissions for nothing is either dead code, or a check of
+    /// e.g. `readonly()` which was forgotten.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # fn f() -> std::io::Result<()> {
+    /// let file = std::fs::File::open("foo.txt")?;
+    /// let _permissions = file.metadata()?.permissions();
+    /// file.set_len(0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # fn f() -> std::io::Result<()> {
+    /// let file = std::fs::File::open("foo.txt")?;
+    /// if !file.metadata()?.permissions().readonly() {
+    ///     file.set_len(0)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub READONLY_METADATA_IGNORED,
+    suspicious,
+    "`Permissions` fetched from metadata which are never used"
+}
+
+declare_lint_pass!(ReadonlyMetadataIgnored => [READONLY_METADATA_IGNORED]);
+
+impl<'tcx> LateLintPass<'tcx> for ReadonlyMetadataIgnored {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'_>) {
+        if_chain! {
+            if !local.span.from_expansion();
+            if let PatKind::Binding(_, local_id, _, None) = local.pat.kind;
+            if let Some(init) = local.init;
+            if let ExprKind::MethodCall(path, [_], _) = init.kind;
+            if path.ident.name == sym!(permissions);
+            if match_type(cx, cx.typeck_results().expr_ty(init), &paths::PERMISSIONS);
+            if let Some(body_id) = cx.enclosing_body;
+            if !is_used(cx, &cx.tcx.hir().body(body_id).value, local_id);
+            then {
+                span_lint_and_help(
+                    cx,
+                    READONLY_METADATA_IGNORED,
+                    local.span,
+                    "these permissions are never used",
+                    None,
+                    "check them, e.g. with `readonly()`, or remove this statement",
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the binding is used for anything other than being discarded with
+/// `let _ = permissions` or `drop(permissions)`.
+fn is_used<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'_>, local_id: HirId) -> bool {
+    let mut used = false;
+    expr_visitor(cx, |e| {
+        if !used && path_to_local_id(e, local_id) {
+            let dropped = matches!(
+                get_parent_expr(cx, e),
+                Some(Expr { kind: ExprKind::Call(fun, [_]), .. })
+                    if path_res(cx, fun).opt_def_id() == cx.tcx.get_diagnostic_item(sym::mem_drop)
+            );
+            let ignored = matches!(
+                get_parent_node(cx.tcx, e.hir_id),
+                Some(Node::Local(Local { pat, .. })) if matches!(pat.kind, PatKind::Wild)
+            );
+            used = !dropped && !ignored;
+        }
+        !used
+    })
+    .visit_expr(body);
+    used
+}
@@ -0,0 +1,78 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{path_to_local, path_to_local_id};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tuple literals which take every field of another tuple in
+    /// order, like `(t.0, t.1)` where `t` is a 2-tuple.
+    ///
+    /// ### Why is this bad?
+    /// The new tuple is the same as `t`, so it can be used directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let t = (1, "one");
+    /// let u = (t.0, t.1);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let t = (1, "one");
+    /// let u = t;
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub REDUNDANT_TUPLE_REBUILD,
+    complexity,
+    "rebuilding a tuple from all of its fields in order"
+}
+
+declare_lint_pass!(RedundantTupleRebuild => [REDUNDANT_TUPLE_REBUILD]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantTupleRebuild {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Tup(elems @ [Expr { kind: ExprKind::Field(base, _), .. }, ..]) = expr.kind;
+            if let Some(local_id) = path_to_local(base);
+            if elems.iter().enumerate().all(|(i, elem)| is_field_of_local(elem, local_id, i));
+            let base_ty = cx.typeck_results().expr_ty(base);
+            // Fields can only be moved out of an owned tuple, so the fields of a
+            // tuple behind a reference are `Copy`, and so is the tuple.
+            let (tuple_ty, deref) = match *base_ty.kind() {
+                ty::Ref(_, inner_ty, _) => (inner_ty, "*"),
+                _ => (base_ty, ""),
+            };
+            // Also rules out tuples which only use some of the fields, or coerce one of them.
+            if tuple_ty == cx.typeck_results().expr_ty(expr);
+            then {
+                // If the tuple isn't `Copy`, all of its fields are moved out of it, which is
+                // the same as moving the tuple itself.
+                let mut applicability = Applicability::MachineApplicable;
+                let base_snippet = snippet_with_applicability(cx, base.span, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    REDUNDANT_TUPLE_REBUILD,
+                    expr.span,
+                    "this tuple is rebuilt from all of the fields of another one",
+                    "try",
+                    format!("{}{}", deref, base_snippet),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` is the `index`th field of the local `local_id`, e.g. `t.1` for `index == 1`.
+fn is_field_of_local(expr: &Expr<'_>, local_id: HirId, index: usize) -> bool {
+    match expr.kind {
+        ExprKind::Field(base, ident) => path_to_local_id(base, local_id) && ident.as_str() == index.to_string(),
+        _ => false,
+    }
+}
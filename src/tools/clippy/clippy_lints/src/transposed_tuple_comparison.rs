@@ -0,0 +1,91 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::source::snippet;
+use if_chain::if_chain;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for ordering comparisons between tuple literals whose elements
+    /// have the same names on both sides, but in a different order.
+    ///
+    /// ### Why is this bad?
+    /// Tuples are compared lexicographically, so the first elements are
+    /// compared first. When the elements are transposed on one side, values
+    /// with different meanings are compared to each other, which is likely a bug.
+    ///
+    /// ### Known problems
+    /// This is a heuristic based on the names of variables and fields.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # struct Version { major: u32, minor: u32 }
+    /// # let (a, b) = (Version { major: 1, minor: 0 }, Version { major: 1, minor: 2 });
+    /// let older = (a.minor, a.major) < (b.major, b.minor);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Version { major: u32, minor: u32 }
+    /// # let (a, b) = (Version { major: 1, minor: 0 }, Version { major: 1, minor: 2 });
+    /// let older = (a.major, a.minor) < (b.major, b.minor);
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub TRANSPOSED_TUPLE_COMPARISON,
+    suspicious,
+    "ordering comparison of tuples whose elements are in a different order on each side"
+}
+
+declare_lint_pass!(TransposedTupleComparison => [TRANSPOSED_TUPLE_COMPARISON]);
+
+impl<'tcx> LateLintPass<'tcx> for TransposedTupleComparison {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Binary(op, lhs, rhs) = expr.kind;
+            if matches!(op.node, BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge);
+            if let ExprKind::Tup(lhs_elems) = lhs.kind;
+            if let ExprKind::Tup(rhs_elems) = rhs.kind;
+            if lhs_elems.len() == rhs_elems.len();
+            if let Some(lhs_names) = lhs_elems.iter().map(element_name).collect::<Option<Vec<_>>>();
+            if let Some(rhs_names) = rhs_elems.iter().map(element_name).collect::<Option<Vec<_>>>();
+            if lhs_names != rhs_names && is_permutation(&lhs_names, &rhs_names);
+            if let Some(i) = lhs_names.iter().zip(&rhs_names).position(|(l, r)| l != r);
+            then {
+                span_lint_and_note(
+                    cx,
+                    TRANSPOSED_TUPLE_COMPARISON,
+                    expr.span,
+                    "the elements of these tuples are in a different order",
+                    None,
+                    &format!(
+                        "tuples are compared element by element, so `{}` is compared to `{}`",
+                        snippet(cx, lhs_elems[i].span, ".."),
+                        snippet(cx, rhs_elems[i].span, ".."),
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// The name of a variable (`a`) or the field of a field access (`x.a`).
+fn element_name(expr: &Expr<'_>) -> Option<Symbol> {
+    match expr.kind {
+        ExprKind::Path(QPath::Resolved(None, path)) => match path.segments {
+            [segment] => Some(segment.ident.name),
+            _ => None,
+        },
+        ExprKind::Field(_, ident) => Some(ident.name),
+        _ => None,
+    }
+}
+
+fn is_permutation(lhs: &[Symbol], rhs: &[Symbol]) -> bool {
+    let mut lhs = lhs.to_vec();
+    let mut rhs = rhs.to_vec();
+    lhs.sort_unstable();
+    rhs.sort_unstable();
+    lhs == rhs
+}
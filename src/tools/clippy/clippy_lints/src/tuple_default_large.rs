@@ -0,0 +1,76 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{any_parent_is_automatically_derived, match_def_path, paths};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Default::default()` calls producing a tuple with many
+    /// elements. The threshold can be configured with `large-tuple-default-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// The elements of a large tuple can only be told apart by their position,
+    /// which makes the code hard to read. A named struct deriving `Default`
+    /// is clearer, and isn't limited to the tuple sizes `Default` is implemented for.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let (a, b, c, d, e, f, g, h): (u8, u8, u8, u8, u8, u8, u8, u8) = Default::default();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Default)]
+    /// struct Config {
+    ///     width: u8,
+    ///     height: u8,
+    ///     // ...
+    /// }
+    ///
+    /// let config = Config::default();
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub TUPLE_DEFAULT_LARGE,
+    pedantic,
+    "using `Default::default()` to build a large tuple"
+}
+
+pub struct TupleDefaultLarge {
+    threshold: u64,
+}
+
+impl TupleDefaultLarge {
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl_lint_pass!(TupleDefaultLarge => [TUPLE_DEFAULT_LARGE]);
+
+impl<'tcx> LateLintPass<'tcx> for TupleDefaultLarge {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Call(path, []) = expr.kind;
+            if let ExprKind::Path(ref qpath) = path.kind;
+            if let Some(def_id) = cx.qpath_res(qpath, path.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+            if let ty::Tuple(tys) = cx.typeck_results().expr_ty(expr).kind();
+            if tys.len() as u64 >= self.threshold;
+            if !any_parent_is_automatically_derived(cx.tcx, expr.hir_id);
+            then {
+                span_lint_and_help(
+                    cx,
+                    TUPLE_DEFAULT_LARGE,
+                    expr.span,
+                    &format!("using `Default::default()` to build a tuple with {} elements", tys.len()),
+                    None,
+                    "consider using a named struct with `#[derive(Default)]` instead",
+                );
+            }
+        }
+    }
+}
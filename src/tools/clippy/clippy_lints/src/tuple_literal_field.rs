@@ -0,0 +1,89 @@
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_sugg};
+use clippy_utils::sugg::Sugg;
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for accesses to a field of a tuple literal, like `(x, y).0`.
+    ///
+    /// ### Why is this bad?
+    /// Building a tuple only to take one of its elements is needlessly
+    /// convoluted, the element can be used directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (x, y) = (1, 2);
+    /// let z = (x, y).0;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (x, y) = (1, 2);
+    /// let z = x;
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub TUPLE_LITERAL_FIELD,
+    complexity,
+    "accessing a field of a tuple literal"
+}
+
+declare_lint_pass!(TupleLiteralField => [TUPLE_LITERAL_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for TupleLiteralField {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Field(tuple, field) = expr.kind;
+            if let ExprKind::Tup(elems) = tuple.kind;
+            if let Ok(index) = field.as_str().parse::<usize>();
+            if let Some(elem) = elems.get(index);
+            then {
+                let msg = "accessing a field of a tuple literal";
+                let others_are_pure = elems
+                    .iter()
+                    .enumerate()
+                    .all(|(i, other)| i == index || is_side_effect_free(cx, other));
+                if others_are_pure {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let sugg = Sugg::hir_with_applicability(cx, elem, "..", &mut applicability).maybe_par();
+                    span_lint_and_sugg(
+                        cx,
+                        TUPLE_LITERAL_FIELD,
+                        expr.span,
+                        msg,
+                        "use the element directly",
+                        sugg.to_string(),
+                        applicability,
+                    );
+                } else {
+                    span_lint_and_note(
+                        cx,
+                        TUPLE_LITERAL_FIELD,
+                        expr.span,
+                        msg,
+                        None,
+                        "the other elements may have side effects, which must be kept when using the element directly",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Conservatively checks that evaluating `expr` has no side effects, i.e. that it only consists
+/// of literals, paths, field accesses, references and tuples of those.
+///
+/// A path to a value which needs to be dropped isn't free of side effects: moving it into the
+/// tuple drops it at the end of the statement, e.g. it would unlock the mutex of a `MutexGuard`.
+fn is_side_effect_free(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(_) => !cx.typeck_results().expr_ty(expr).needs_drop(cx.tcx, cx.param_env),
+        ExprKind::Field(e, _) | ExprKind::AddrOf(_, _, e) => is_side_effect_free(cx, e),
+        ExprKind::Tup(elems) => elems.iter().all(|e| is_side_effect_free(cx, e)),
+        _ => false,
+    }
+}
@@ -310,6 +310,10 @@ pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// the slice pattern that is suggested. If more elements would be necessary, the lint is suppressed.
     /// For example, `[_, _, _, e, ..]` is a slice pattern with 4 elements.
     (max_suggested_slice_pattern_length: u64 = 3),
+    /// Lint: TUPLE_DEFAULT_LARGE.
+    ///
+    /// The minimum number of elements of a tuple for `Default::default()` to be linted
+    (large_tuple_default_threshold: u64 = 8),
 }
 
 /// Search for the configuration file.
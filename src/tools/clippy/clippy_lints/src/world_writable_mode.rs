@@ -0,0 +1,68 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::paths;
+use clippy_utils::ty::match_type;
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for literal modes with the "write" bit for other users (`0o002`)
+    /// set, passed to `OpenOptionsExt::mode` or `DirBuilderExt::mode`.
+    ///
+    /// ### Why is this bad?
+    /// Any user on the system can modify the created file or directory, which
+    /// is rarely intended and a common source of security issues.
+    ///
+    /// ### Known problems
+    /// The process umask is applied to the mode, and usually clears the bit.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(0o777);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(0o755);
+    /// ```
+    #[clippy::version = "1.62.0"]
+    pub WORLD_WRITABLE_MODE,
+    pedantic,
+    "creating files or directories which every user can write to"
+}
+
+declare_lint_pass!(WorldWritableMode => [WORLD_WRITABLE_MODE]);
+
+impl<'tcx> LateLintPass<'tcx> for WorldWritableMode {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(path, [recv, param], _) = expr.kind;
+            if path.ident.name == sym!(mode);
+            let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+            if match_type(cx, recv_ty, &paths::OPEN_OPTIONS) || match_type(cx, recv_ty, &paths::DIR_BUILDER);
+            if let ExprKind::Lit(lit) = &param.kind;
+            if let LitKind::Int(mode, _) = lit.node;
+            if mode & 0o002 != 0;
+            then {
+                span_lint_and_note(
+                    cx,
+                    WORLD_WRITABLE_MODE,
+                    param.span,
+                    "this mode makes the created file or directory writable by every user",
+                    None,
+                    "on Unix platforms, any user on the system can then modify it",
+                );
+            }
+        }
+    }
+}
@@ -149,6 +149,10 @@
 pub const STR_ENDS_WITH: [&str; 4] = ["core", "str", "<impl str>", "ends_with"];
 pub const STR_FROM_UTF8: [&str; 4] = ["core", "str", "converts", "from_utf8"];
 pub const STR_LEN: [&str; 4] = ["core", "str", "<impl str>", "len"];
+pub const STR_RSPLIT: [&str; 4] = ["core", "str", "iter", "RSplit"];
+pub const STR_RSPLIT_TERMINATOR: [&str; 4] = ["core", "str", "iter", "RSplitTerminator"];
+pub const STR_SPLIT: [&str; 4] = ["core", "str", "iter", "Split"];
+pub const STR_SPLIT_TERMINATOR: [&str; 4] = ["core", "str", "iter", "SplitTerminator"];
 pub const STR_STARTS_WITH: [&str; 4] = ["core", "str", "<impl str>", "starts_with"];
 #[cfg(feature = "internal")]
 pub const SYMBOL: [&str; 3] = ["rustc_span", "symbol", "Symbol"];
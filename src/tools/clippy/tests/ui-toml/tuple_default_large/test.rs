@@ -0,0 +1,6 @@
+#![warn(clippy::tuple_default_large)]
+
+fn main() {
+    let _: (u8, u8, u8) = Default::default();
+    let _: (u8, u8) = Default::default();
+}
@@ -1,3 +1,4 @@
+#![feature(bool_to_option)]
 #![warn(clippy::all, clippy::pedantic)]
 
 fn main() {
@@ -14,4 +15,13 @@ fn main() {
             }
         })
         .next();
+
+    // the `filter` closure doesn't take a `&x` pattern, so the closures can't be merged
+    let _ = a.iter().filter(|s| s.len() > 1).map(|s| s.len()).next();
+
+    // the closure returns a nested `Option`
+    let _ = a.iter().filter_map(|s| Some(s.parse::<u32>().ok())).next();
+
+    // `String` isn't `Copy`, so the predicate has to take it by reference
+    let _ = vec![String::new()].into_iter().filter_map(|s| s.is_empty().then_some(s)).next();
 }
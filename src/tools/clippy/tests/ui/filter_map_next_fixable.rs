@@ -1,5 +1,6 @@
 // run-rustfix
 
+#![feature(bool_to_option)]
 #![warn(clippy::all, clippy::pedantic)]
 
 fn main() {
@@ -7,4 +8,22 @@ fn main() {
 
     let element: Option<i32> = a.iter().filter_map(|s| s.parse().ok()).next();
     assert_eq!(element, Some(1));
+
+    let doubled = (0..10).filter(|&x| x > 2).map(|x| x * 2).next();
+    assert_eq!(doubled, Some(6));
+
+    let trimmed: Option<i32> = a
+        .iter()
+        .filter_map(|s| {
+            let s = s.trim();
+            s.parse().ok()
+        })
+        .next();
+    assert_eq!(trimmed, Some(1));
+
+    let large = (0..10).filter_map(|x| (x > 2).then_some(x)).next();
+    assert_eq!(large, Some(3));
+
+    let large_doubled = (0..10).filter_map(|x| (x > 2).then_some(x * 2)).next();
+    assert_eq!(large_doubled, Some(6));
 }
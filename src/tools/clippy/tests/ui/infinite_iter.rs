@@ -23,6 +23,9 @@ fn infinite_iters() {
     (0_u64..).filter(|x| x % 2 == 0).last(); // infinite iter
     (0..42_u64).by_ref().last(); // not an infinite, because ranges are double-ended
     (0..).next(); // iterator is not exhausted
+    (0..).last(); // infinite iter
+    (0..10).last(); // not infinite
+    repeat(1).count(); // infinite iter
 }
 
 #[deny(clippy::maybe_infinite_iter)]
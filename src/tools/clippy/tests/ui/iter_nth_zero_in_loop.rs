@@ -0,0 +1,24 @@
+// run-rustfix
+
+#![warn(clippy::iter_nth_zero_in_loop)]
+#![allow(clippy::iter_nth_zero, clippy::while_let_on_iterator)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let n = 1;
+
+    let mut iter = v.iter();
+    while let Some(_x) = iter.nth(0) {}
+
+    let mut iter = v.iter();
+    for _ in 0..2 {
+        let _ = iter.nth(0);
+        // not `0`
+        let _ = iter.nth(1);
+        // not a literal
+        let _ = iter.nth(n);
+    }
+
+    // not in a loop
+    let _ = v.iter().nth(0);
+}
@@ -0,0 +1,17 @@
+// run-rustfix
+#![warn(clippy::manual_tuple_default)]
+#![allow(unused)]
+
+const ORIGIN: (u8, u8) = (0, 0);
+
+fn main() {
+    let state = (0, String::new(), None::<u8>);
+    let annotated: (u8, Vec<u8>, &str) = (0, vec![], "");
+    let nested = ((0, false), 0u64);
+
+    // Not every element is the default value.
+    let partial = (0, String::from("a"), None::<u8>);
+
+    // Tuples with more than 12 elements don't implement `Default`.
+    let long = (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+}
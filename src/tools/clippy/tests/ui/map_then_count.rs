@@ -0,0 +1,25 @@
+// run-rustfix
+#![warn(clippy::map_then_count)]
+
+fn main() {
+    let _ = (0..3).map(|x| x + 2).count();
+
+    let v = vec![1, 2, 3];
+    let _ = v.iter().skip(1).map(|x| (x, *x > 1)).count();
+
+    // The closure may have side effects, which is left to `suspicious_map`
+    let _ = v.iter().map(|x| x.to_string()).count();
+    let _ = v.iter().map(ToString::to_string).count();
+
+    // Neither lint fires when the closure mutates a captured variable
+    let mut n = 0;
+    let _ = v
+        .iter()
+        .map(|x| {
+            n += 1;
+            x
+        })
+        .count();
+
+    let _ = v.iter().filter(|&&x| x > 1).count();
+}
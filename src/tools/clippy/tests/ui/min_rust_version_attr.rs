@@ -88,6 +88,7 @@ pub fn filter_map_next() {
             }
         })
         .next();
+    let _ = (0..10).filter(|&x| x > 2).map(|x| x * 2).next();
 }
 
 #[allow(clippy::no_effect)]
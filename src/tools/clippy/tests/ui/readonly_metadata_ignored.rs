@@ -0,0 +1,34 @@
+#![warn(clippy::readonly_metadata_ignored)]
+use std::fs::{self, File};
+use std::io;
+
+fn ignored(path: &str) -> io::Result<()> {
+    let file = File::open(path)?;
+    let _permissions = file.metadata()?.permissions();
+
+    let permissions = fs::metadata(path)?.permissions();
+    let _ = permissions;
+
+    let permissions = fs::metadata(path)?.permissions();
+    drop(permissions);
+    Ok(())
+}
+
+fn used(path: &str) -> io::Result<bool> {
+    let file = File::open(path)?;
+    let permissions = file.metadata()?.permissions();
+    if permissions.readonly() {
+        return Ok(false);
+    }
+
+    let permissions = fs::metadata(path)?.permissions();
+    fs::set_permissions(path, permissions)?;
+
+    let permissions = fs::metadata(path)?.permissions();
+    Ok(permissions.readonly())
+}
+
+fn main() {
+    let _ = ignored("foo.txt");
+    let _ = used("foo.txt");
+}
@@ -0,0 +1,30 @@
+// run-rustfix
+
+#![warn(clippy::redundant_tuple_rebuild)]
+
+fn main() {
+    let t = (1, 2);
+    let _copied = (t.0, t.1);
+
+    let r = &t;
+    let _copied = (r.0, r.1);
+
+    let owned = (String::from("one"), vec![1]);
+    let _moved = (owned.0, owned.1);
+
+    // reordered fields
+    let _swapped = (t.1, t.0);
+
+    // only some of the fields
+    let triple = (1, 2, 3);
+    let _pair = (triple.0, triple.1);
+    let _mixed = (t.0, 3);
+
+    // fields of different tuples
+    let u = (3, 4);
+    let _mixed = (t.0, u.1);
+
+    // the fields are coerced
+    let refs = (&mut 1, &mut 2);
+    let _shared: (&i32, &i32) = (refs.0, refs.1);
+}
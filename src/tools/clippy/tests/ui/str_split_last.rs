@@ -0,0 +1,18 @@
+// run-rustfix
+#![warn(clippy::str_split_last)]
+
+fn main() {
+    let s = "Hello world, how are you";
+
+    let _ = s.split(' ').last();
+    let _ = s.rsplit(',').last();
+    let _ = s.split_terminator(char::is_whitespace).last();
+
+    // `SplitN` isn't a `DoubleEndedIterator`
+    let _ = s.splitn(2, ' ').last();
+    // `&str` patterns can't be searched from the back
+    let _ = s.split(", ").last();
+    // `next_back` would need a mutable binding, and leaves the iterator unconsumed
+    let words = s.split(' ');
+    let _ = words.last();
+}
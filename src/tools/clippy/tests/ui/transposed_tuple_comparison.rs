@@ -0,0 +1,29 @@
+#![warn(clippy::transposed_tuple_comparison)]
+
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+fn main() {
+    let a = Version { major: 1, minor: 0 };
+    let b = Version { major: 1, minor: 2 };
+    let _ = (a.minor, a.major) < (b.major, b.minor);
+    let _ = (a.major, a.minor) >= (b.minor, b.major);
+
+    let (x, y) = (1, 2);
+    let _ = (x, y) <= (y, x);
+
+    // Same order on both sides
+    let _ = (a.major, a.minor) < (b.major, b.minor);
+
+    // Different names
+    let (z, w) = (3, 4);
+    let _ = (y, x) < (w, z);
+
+    // Not an ordering comparison
+    let _ = (x, y) == (y, x);
+
+    // Not only names
+    let _ = (x, y + 1) < (y, x);
+}
@@ -0,0 +1,16 @@
+#![warn(clippy::tuple_default_large)]
+
+#[derive(Default)]
+struct Wrapper((u8, u8, u8, u8, u8, u8, u8, u8, u8));
+
+fn main() {
+    let _: (u8, u8, u8, u8, u8, u8, u8, u8, u8) = Default::default();
+    let _ = <(u8, u8, u8, u8, u8, u8, u8, u8, u8)>::default();
+
+    // Not enough elements
+    let _: (u8, u8, u8) = Default::default();
+    let _: (u8, u8, u8, u8, u8, u8, u8) = Default::default();
+
+    // Not a `Default::default()` call
+    let _ = Wrapper::default();
+}
@@ -0,0 +1,32 @@
+// run-rustfix
+#![warn(clippy::tuple_literal_field)]
+#![allow(unused)]
+
+fn f() -> u32 {
+    1
+}
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    let (x, y) = (1u32, 2u32);
+    let _ = (x, y).0;
+    let _ = (x, y).1 + 1;
+    let _ = (x + y, y).0 * 2;
+    let _ = ((x, y), &x).0;
+
+    // The call must not be lost
+    let _ = (f(), y).1;
+
+    // `guard` must not be dropped before the end of its scope
+    let guard = Guard;
+    let _ = (x, guard).0;
+
+    // Not a tuple literal
+    let t = (x, y);
+    let _ = t.0;
+}
@@ -0,0 +1,40 @@
+// ignore-windows
+#![warn(clippy::unapplied_permissions)]
+use std::fs::{self, File, Permissions};
+use std::io;
+use std::os::unix::fs::PermissionsExt;
+
+fn not_applied(path: &str) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(true);
+
+    let file = File::open(path)?;
+    let mut permissions = file.metadata()?.permissions();
+    permissions.set_mode(0o644);
+    Ok(())
+}
+
+fn applied(path: &str) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(path, permissions)?;
+
+    let file = File::open(path)?;
+    let mut permissions = file.metadata()?.permissions();
+    if permissions.mode() & 0o200 == 0 {
+        permissions.set_mode(0o644);
+    }
+    file.set_permissions(permissions)
+}
+
+fn returned(path: &str) -> io::Result<Permissions> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(true);
+    Ok(permissions)
+}
+
+fn not_a_copy(permissions: &mut Permissions) {
+    permissions.set_readonly(true);
+}
+
+fn main() {}
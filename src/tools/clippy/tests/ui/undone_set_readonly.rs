@@ -0,0 +1,20 @@
+#![warn(clippy::undone_set_readonly)]
+use std::fs;
+use std::io;
+
+fn back_to_back(path: &str) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(true);
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions)
+}
+
+fn applied_in_between(path: &str) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(path, permissions.clone())?;
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions)
+}
+
+fn main() {}
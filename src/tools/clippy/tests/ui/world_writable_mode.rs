@@ -0,0 +1,19 @@
+// ignore-windows
+#![warn(clippy::world_writable_mode)]
+use std::fs::{DirBuilder, OpenOptions};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+
+fn main() {
+    let mode = 0o777;
+
+    let mut options = OpenOptions::new();
+    options.mode(0o777);
+    options.mode(0o666);
+    options.mode(0o644);
+    // Not a literal.
+    options.mode(mode);
+
+    let mut builder = DirBuilder::new();
+    builder.mode(0o757);
+    builder.mode(0o755);
+}
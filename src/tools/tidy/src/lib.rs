@@ -56,6 +56,7 @@ macro_rules! tidy_error {
 fn filter_dirs(path: &Path) -> bool {
     let skip = [
         "tidy-test-file",
+        "src/tools/tidy/src/ui_tests/fixtures",
         "compiler/rustc_codegen_cranelift",
         "compiler/rustc_codegen_gcc",
         "src/llvm-project",
@@ -1,14 +1,73 @@
 //! Tidy check to ensure below in UI test directories:
-//! - the number of entries in each directory must be less than `ENTRY_LIMIT`
-//! - there are no stray `.stderr` files
+//! - the number of entries in each directory must be less than `ENTRY_LIMIT`, or the
+//!   limit given in the directory's `.tidy-entry-limit` file
+//! - there are no stray `.stderr` files (or `.svg` files, their annotate-snippets equivalent)
+//!   or `.mir` dumps
+//! - `.fixed` files belong to a `// run-rustfix` test
+//! - `.ftl` files belong to a translation test
+//! - test sources are not empty
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+#[cfg(test)]
+mod tests;
+
 const ENTRY_LIMIT: usize = 1000;
 // FIXME: The following limits should be reduced eventually.
 const ROOT_ENTRY_LIMIT: usize = 986;
 const ISSUES_ENTRY_LIMIT: usize = 2310;
+/// A file containing a single integer, which overrides `ENTRY_LIMIT` for the
+/// directory it is in (and counts as one of its entries).
+const ENTRY_LIMIT_FILE: &str = ".tidy-entry-limit";
+
+/// Groups the given file names by their leading word (e.g. `borrowck` for
+/// `borrowck-closures-two-mut.rs`), and returns the (at most two) largest groups
+/// with their size, as candidates of subdirectories to move the files to.
+fn suggest_subdirectories<'a>(names: impl Iterator<Item = &'a str>) -> Vec<(&'a str, usize)> {
+    let mut groups = HashMap::new();
+    for name in names {
+        let stem = name.split('.').next().unwrap();
+        let word = stem.split(['-', '_']).next().unwrap();
+        // A file without any separator is not part of a group.
+        if !word.is_empty() && word != stem {
+            *groups.entry(word).or_insert(0) += 1;
+        }
+    }
+    let mut groups: Vec<_> = groups.into_iter().filter(|&(_, count)| count > 1).collect();
+    // Sort by name as well, so that the suggestion doesn't depend on the `HashMap` order.
+    groups.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    groups.truncate(2);
+    groups
+}
+
+/// Reads the entry limit override of a directory, if it has an `ENTRY_LIMIT_FILE`.
+fn entry_limit_override(dir_path: &Path) -> Result<Option<usize>, String> {
+    let file_path = dir_path.join(ENTRY_LIMIT_FILE);
+    match fs::read_to_string(&file_path) {
+        Ok(contents) => contents.trim().parse().map(Some).map_err(|_| {
+            format!("{:?} must contain a single integer, found {:?}", file_path, contents.trim())
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Checks that an entry limit override is actually needed for a directory with
+/// `count` entries, and that it isn't higher than it has to be.
+fn check_entry_limit_override(limit: usize, count: usize) -> Result<(), String> {
+    if count <= ENTRY_LIMIT {
+        Err(format!(
+            "`{}` is unnecessary, the directory is within the default limit of {} entries \
+            (current: {}), remove it",
+            ENTRY_LIMIT_FILE, ENTRY_LIMIT, count
+        ))
+    } else if count < limit {
+        Err(format!("`{}` is too high (is {}, should be {})", ENTRY_LIMIT_FILE, limit, count))
+    } else {
+        Ok(())
+    }
+}
 
 fn check_entries(path: &Path, bad: &mut bool) {
     let dirs = walkdir::WalkDir::new(&path.join("test/ui"))
@@ -21,21 +80,49 @@ fn check_entries(path: &Path, bad: &mut bool) {
             // Use special values for these dirs.
             let is_root = path.join("test/ui") == dir_path;
             let is_issues_dir = path.join("test/ui/issues") == dir_path;
+            let limit_override = if is_root || is_issues_dir {
+                None
+            } else {
+                entry_limit_override(dir_path).unwrap_or_else(|err| {
+                    tidy_error!(bad, "{}", err);
+                    None
+                })
+            };
             let limit = if is_root {
                 ROOT_ENTRY_LIMIT
             } else if is_issues_dir {
                 ISSUES_ENTRY_LIMIT
             } else {
-                ENTRY_LIMIT
+                limit_override.unwrap_or(ENTRY_LIMIT)
             };
 
             let count = std::fs::read_dir(dir_path).unwrap().count();
+            if let Some(limit) = limit_override {
+                if let Err(err) = check_entry_limit_override(limit, count) {
+                    tidy_error!(bad, "{}: {}", err, dir_path.display());
+                }
+            }
             if count > limit {
+                let names: Vec<_> = std::fs::read_dir(dir_path)
+                    .unwrap()
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .collect();
+                let suggestion = suggest_subdirectories(names.iter().map(|name| name.as_str()))
+                    .iter()
+                    .map(|(word, count)| format!("`{}` ({} entries)", word, count))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                let suggestion = if suggestion.is_empty() {
+                    String::new()
+                } else {
+                    format!(", e.g. {}", suggestion)
+                };
                 tidy_error!(
                     bad,
                     "following path contains more than {} entries, \
-                    you should move the test to some relevant subdirectory (current: {}): {}",
+                    you should move the test to some relevant subdirectory{} (current: {}): {}",
                     limit,
+                    suggestion,
                     count,
                     dir_path.display()
                 );
@@ -44,19 +131,73 @@ fn check_entries(path: &Path, bad: &mut bool) {
     }
 }
 
-pub fn check(path: &Path, bad: &mut bool) {
+/// Whether the source of a test contains a (possibly revision-specific)
+/// `run-rustfix` directive.
+fn has_rustfix_directive(src: &str) -> bool {
+    src.lines().any(|line| {
+        let directive = match line.trim_start().strip_prefix("//") {
+            Some(directive) => directive.trim_start(),
+            None => return false,
+        };
+        // Skip the revision of e.g. `//[foo] run-rustfix`.
+        let directive = match directive.strip_prefix('[') {
+            Some(rest) => rest.split_once(']').map_or("", |(_, directive)| directive),
+            None => directive,
+        };
+        directive.split_whitespace().next() == Some("run-rustfix")
+    })
+}
+
+/// Whether the source of a test passes one of the `-Ztranslate-*` flags.
+fn is_translation_test(src: &str) -> bool {
+    src.lines().any(|line| {
+        let flags = match line.trim_start().strip_prefix("//") {
+            Some(directive) => directive.trim_start().strip_prefix("compile-flags:"),
+            None => None,
+        };
+        flags.map_or(false, |flags| {
+            flags.contains("-Ztranslate-") || flags.contains("-Z translate-")
+        })
+    })
+}
+
+pub fn check(path: &Path, bless: bool, bad: &mut bool) {
     check_entries(&path, bad);
     for path in &[&path.join("test/ui"), &path.join("test/ui-fulldeps")] {
         super::walk_no_read(path, &mut |_| false, &mut |entry| {
             let file_path = entry.path();
             if let Some(ext) = file_path.extension() {
-                if ext == "stderr" || ext == "stdout" {
+                if ext == "ftl" {
+                    let test_path = file_path.with_extension("rs");
+                    let is_paired = fs::read_to_string(&test_path)
+                        .map_or(false, |src| is_translation_test(&src));
+                    if !is_paired {
+                        tidy_error!(
+                            bad,
+                            "Stray `.ftl` file, {:?} is not a translation test: {:?}",
+                            test_path,
+                            file_path
+                        );
+                    }
+                } else if ext == "rs" {
+                    if let Ok(metadata) = fs::metadata(file_path) {
+                        if metadata.len() == 0 {
+                            tidy_error!(bad, "Empty UI test file: {:?}", file_path);
+                        }
+                    }
+                } else if ext == "stderr"
+                    || ext == "stdout"
+                    || ext == "svg"
+                    || ext == "fixed"
+                    || ext == "mir"
+                {
                     // Test output filenames have one of the formats:
                     // ```
                     // $testname.stderr
                     // $testname.$mode.stderr
                     // $testname.$revision.stderr
                     // $testname.$revision.$mode.stderr
+                    // $testname.$mir_opt_pass.mir
                     // ```
                     //
                     // For now, just make sure that there is a corresponding
@@ -66,8 +207,24 @@ pub fn check(path: &Path, bad: &mut bool) {
                     // must strip all of them.
                     let testname =
                         file_path.file_name().unwrap().to_str().unwrap().split_once('.').unwrap().0;
-                    if !file_path.with_file_name(testname).with_extension("rs").exists() {
+                    let test_path = file_path.with_file_name(testname).with_extension("rs");
+                    if !test_path.exists() {
                         tidy_error!(bad, "Stray file with UI testing output: {:?}", file_path);
+                    } else if ext == "fixed" {
+                        let src = fs::read_to_string(&test_path).unwrap();
+                        if !has_rustfix_directive(&src) {
+                            if bless {
+                                fs::remove_file(file_path).unwrap();
+                                return;
+                            }
+                            tidy_error!(
+                                bad,
+                                "Stale `.fixed` file, {:?} is not a `// run-rustfix` test \
+                                (run with `--bless` to remove it): {:?}",
+                                test_path,
+                                file_path
+                            );
+                        }
                     }
 
                     if let Ok(metadata) = fs::metadata(file_path) {
@@ -0,0 +1,5 @@
+// run-rustfix
+
+fn main() {
+    let _x = 1;
+}
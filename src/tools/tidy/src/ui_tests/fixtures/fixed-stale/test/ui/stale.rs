@@ -0,0 +1,5 @@
+// check-pass
+
+fn main() {
+    let _x = 1;
+}
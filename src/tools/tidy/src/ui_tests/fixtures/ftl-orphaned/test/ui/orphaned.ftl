@@ -0,0 +1 @@
+parser-struct-literal-body-without-path = this is a test message
@@ -0,0 +1,3 @@
+// compile-flags: -Ztranslate-additional-ftl=$DIR/paired.ftl
+
+fn main() {}
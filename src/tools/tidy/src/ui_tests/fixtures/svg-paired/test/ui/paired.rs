@@ -0,0 +1,3 @@
+// revisions: a b
+
+fn main() {}
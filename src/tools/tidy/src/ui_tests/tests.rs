@@ -0,0 +1,156 @@
+use super::*;
+
+#[test]
+fn test_suggest_subdirectories() {
+    let names = [
+        "borrowck-closures-two-mut.rs",
+        "borrowck-closures-two-mut.stderr",
+        "borrowck_move.rs",
+        "issue-1234.rs",
+        "issue-1234.stderr",
+        "issue-5678.rs",
+        "lint-unused.rs",
+        "lint-unused.stderr",
+        "main.rs",
+        "auxiliary",
+        "single-file.rs",
+    ];
+    assert_eq!(suggest_subdirectories(names.into_iter()), [("borrowck", 3), ("issue", 3)]);
+}
+
+#[test]
+fn test_suggest_subdirectories_without_groups() {
+    assert!(suggest_subdirectories([].into_iter()).is_empty());
+    assert!(suggest_subdirectories(["main.rs", "lib.rs", "one-off.rs"].into_iter()).is_empty());
+}
+
+#[test]
+fn test_has_rustfix_directive() {
+    assert!(has_rustfix_directive("// run-rustfix\n\nfn main() {}\n"));
+    assert!(has_rustfix_directive("// revisions: a b\n//[a] run-rustfix\nfn main() {}\n"));
+    assert!(has_rustfix_directive("//run-rustfix\nfn main() {}\n"));
+
+    // The `.fixed` file of these is stale.
+    assert!(!has_rustfix_directive("// check-pass\nfn main() {}\n"));
+    assert!(!has_rustfix_directive("// FIXME: should be run-rustfix\nfn main() {}\n"));
+    assert!(!has_rustfix_directive("fn main() { let _ = \"// run-rustfix\"; }\n"));
+}
+
+#[test]
+fn test_is_translation_test() {
+    // The `.ftl` file next to these is paired with the test.
+    assert!(is_translation_test(
+        "// compile-flags: -Ztranslate-additional-ftl=$DIR/test.ftl\nfn main() {}\n"
+    ));
+    assert!(is_translation_test("//compile-flags: -Z translate-lang=en-US\nfn main() {}\n"));
+
+    // The `.ftl` file next to these is an orphan.
+    assert!(!is_translation_test("// compile-flags: -Zunpretty=hir\nfn main() {}\n"));
+    assert!(!is_translation_test("// -Ztranslate-lang=en-US\nfn main() {}\n"));
+}
+
+#[test]
+fn test_empty_test_source() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures/empty-test");
+    let mut bad = false;
+    check(&root, false, &mut bad);
+    assert!(bad);
+}
+
+#[test]
+fn test_entry_limit_override() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures/entry-limit");
+    assert_eq!(entry_limit_override(&root.join("with-override")), Ok(Some(1500)));
+    assert_eq!(entry_limit_override(&root.join("without-override")), Ok(None));
+    assert!(entry_limit_override(&root.join("invalid-override")).is_err());
+}
+
+#[test]
+fn test_check_entry_limit_override() {
+    assert_eq!(check_entry_limit_override(1500, 1500), Ok(()));
+    // The directory has grown past its override, which `check_entries` reports separately.
+    assert_eq!(check_entry_limit_override(1500, 1600), Ok(()));
+
+    // The override is higher than the number of entries.
+    assert!(check_entry_limit_override(1500, 1200).is_err());
+    // The default limit would do.
+    assert!(check_entry_limit_override(1500, ENTRY_LIMIT).is_err());
+}
+
+#[test]
+fn test_svg_output() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures");
+
+    // `paired.a.svg` and `paired.b.svg` are the outputs of the revisions of `paired.rs`.
+    let mut bad = false;
+    check(&fixtures.join("svg-paired"), false, &mut bad);
+    assert!(!bad);
+
+    let mut bad = false;
+    check(&fixtures.join("svg-orphaned"), false, &mut bad);
+    assert!(bad);
+}
+
+#[test]
+fn test_mir_output() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures");
+
+    // `paired.SimplifyCfg-initial.after.mir` is a MIR dump of `paired.rs` after a pass.
+    let mut bad = false;
+    check(&fixtures.join("mir-paired"), false, &mut bad);
+    assert!(!bad);
+
+    let mut bad = false;
+    check(&fixtures.join("mir-orphaned"), false, &mut bad);
+    assert!(bad);
+}
+
+#[test]
+fn test_ftl_files() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures");
+
+    // `paired.rs` loads `paired.ftl` with `-Ztranslate-additional-ftl`.
+    let mut bad = false;
+    check(&fixtures.join("ftl-paired"), false, &mut bad);
+    assert!(!bad);
+
+    let mut bad = false;
+    check(&fixtures.join("ftl-orphaned"), false, &mut bad);
+    assert!(bad);
+}
+
+#[test]
+fn test_fixed_output() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures");
+
+    let mut bad = false;
+    check(&fixtures.join("fixed-paired"), false, &mut bad);
+    assert!(!bad);
+
+    // `stale.rs` used to be a `// run-rustfix` test.
+    let mut bad = false;
+    check(&fixtures.join("fixed-stale"), false, &mut bad);
+    assert!(bad);
+    assert!(fixtures.join("fixed-stale/test/ui/stale.fixed").exists());
+}
+
+#[test]
+fn test_bless_removes_stale_fixed_output() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui_tests/fixtures");
+    // Blessing removes files, so it runs on a copy of the fixture.
+    let root = std::env::temp_dir().join(format!("tidy-fixed-stale-{}", std::process::id()));
+    let ui = root.join("test/ui");
+    fs::create_dir_all(&ui).unwrap();
+    for name in ["stale.rs", "stale.fixed"] {
+        fs::copy(fixtures.join("fixed-stale/test/ui").join(name), ui.join(name)).unwrap();
+    }
+
+    let mut bad = false;
+    check(&root, true, &mut bad);
+    let (test_exists, fixed_exists) =
+        (ui.join("stale.rs").exists(), ui.join("stale.fixed").exists());
+    fs::remove_dir_all(&root).unwrap();
+    assert!(!bad);
+    assert!(test_exists);
+    assert!(!fixed_exists);
+}